@@ -0,0 +1,31 @@
+//! Times every day and part on its real puzzle input.
+//!
+//! Criterion keeps the previous run under `target/criterion` and reports the change
+//! against it. To compare against a fixed point instead, save a named baseline first:
+//!
+//!     cargo bench -p aoc -- --save-baseline before
+//!     cargo bench -p aoc -- --baseline before
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc::days::DAYS;
+use common::read_input;
+
+fn bench_days(c: &mut Criterion) {
+    for day in DAYS {
+        let input = read_input(aoc::workspace_root().join(day.input))
+            .unwrap_or_else(|e| panic!("could not read {}: {}", day.input, e));
+
+        let mut group = c.benchmark_group(format!("day{}", day.day));
+        group.sample_size(10);
+        for part in 1..=2 {
+            if let Some(solver) = day.part(part) {
+                group.bench_function(format!("part{}", part), |b| b.iter(|| solver(&input).unwrap()));
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,65 @@
+//! Checked-in expected answers, one per line as `day part input answer`
+//!
+//! Blank lines and lines starting with `#` are ignored. Inputs are relative to the workspace
+//! root, and newlines in multi-line answers are written as `\n`.
+
+use common::{parse_at, ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+impl Answer {
+    pub fn to_line(&self) -> String {
+        format!("{} {} {} {}", self.day, self.part, self.input, self.answer.replace('\n', "\\n"))
+    }
+}
+
+pub fn parse(contents: &str) -> Result<Vec<Answer>, ParseError> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.splitn(4, ' ');
+            let mut field = |expected: &str| {
+                fields
+                    .next()
+                    .filter(|field| !field.is_empty())
+                    .ok_or_else(|| ParseError::after(contents, line, expected))
+            };
+            let day = field("a day")?;
+            let part = field("a part")?;
+            let input = field("an input path")?;
+            let answer = field("an answer")?;
+            Ok(Answer {
+                day: parse_at(contents, day, "a day number")?,
+                part: parse_at(contents, part, "a part number")?,
+                input: input.to_string(),
+                answer: answer.replace("\\n", "\n"),
+            })
+        })
+        .collect()
+}
+
+/// Line by line diff of two answer lists, empty if they match
+pub fn diff(expected: &[Answer], actual: &[Answer]) -> String {
+    let mut diff = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => (),
+            (e, a) => {
+                if let Some(e) = e {
+                    diff += &format!("-{}\n", e.to_line());
+                }
+                if let Some(a) = a {
+                    diff += &format!("+{}\n", a.to_line());
+                }
+            }
+        }
+    }
+    diff
+}
@@ -0,0 +1,48 @@
+use common::ParseError;
+
+/// Solves one part of a day, rendering the typed answer for display
+pub type Solver = fn(&str) -> Result<String, ParseError>;
+
+macro_rules! solver {
+    ($solve:path) => {
+        |input| $solve(input).map(|answer| answer.to_string())
+    };
+}
+
+pub struct Day {
+    pub day: u8,
+    /// Puzzle input relative to the workspace root
+    pub input: &'static str,
+    pub part1: Solver,
+    pub part2: Option<Solver>,
+}
+
+pub const DAYS: &[Day] = &[
+    Day { day: 1, input: "day1/input.txt", part1: solver!(day1::solve_part1), part2: Some(solver!(day1::solve_part2)) },
+    Day { day: 2, input: "day2/input", part1: solver!(day2::solve_part1), part2: Some(solver!(day2::solve_part2)) },
+    Day { day: 3, input: "day3/input", part1: solver!(day3::solve_part1), part2: Some(solver!(day3::solve_part2)) },
+    Day { day: 4, input: "day4/input", part1: solver!(day4::solve_part1), part2: Some(solver!(day4::solve_part2)) },
+    Day { day: 5, input: "day5/input", part1: solver!(day5::solve_part1), part2: Some(solver!(day5::solve_part2)) },
+    Day { day: 6, input: "day6/input", part1: solver!(day6::solve_part1), part2: Some(solver!(day6::solve_part2)) },
+    Day { day: 7, input: "day7/input", part1: solver!(day7::solve_part1), part2: Some(solver!(day7::solve_part2)) },
+    Day { day: 8, input: "day8/input", part1: solver!(day8::solve_part1), part2: Some(solver!(day8::solve_part2)) },
+    Day { day: 9, input: "day9/input", part1: solver!(day9::solve_part1), part2: Some(solver!(day9::solve_part2)) },
+    Day { day: 10, input: "day10/input", part1: solver!(day10::solve_part1), part2: Some(solver!(day10::solve_part2)) },
+    Day { day: 11, input: "day11/input", part1: solver!(day11::solve_part1), part2: Some(solver!(day11::solve_part2)) },
+    Day { day: 12, input: "day12/input", part1: solver!(day12::solve_part1), part2: Some(solver!(day12::solve_part2)) },
+    Day { day: 13, input: "day13/input", part1: solver!(day13::solve_part1), part2: None },
+];
+
+impl Day {
+    pub fn part(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part1),
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
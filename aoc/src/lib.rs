@@ -0,0 +1,14 @@
+//! Registry of every day's solvers, shared by the `aoc` runner, tests and benchmarks
+
+use std::path::{Path, PathBuf};
+
+pub mod answers;
+pub mod days;
+pub mod scaffold;
+pub mod visuals;
+
+/// Root of the workspace, which puzzle inputs and answer files are relative to
+pub fn workspace_root() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf()
+}
@@ -0,0 +1,339 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+use aoc::answers::{self, Answer};
+use aoc::{days, scaffold, visuals};
+use clap::{Parser, Subcommand, ValueEnum};
+use common::{input_name, read_input, ParseError};
+use grid::image::{self, Frames};
+use serde::{Deserialize, Serialize};
+
+mod watch;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show the solvers' debug output on stderr, or every trace event with -vv
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver for a single day
+    Run {
+        #[arg(long)]
+        day: u8,
+        /// Only run this part, otherwise both parts are run
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle inputs, `-` reads stdin; answers are printed per file
+        #[arg(long, num_args = 1.., required = true)]
+        input: Vec<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Run every day on its puzzle input and check the answers against the stored ones
+    All {
+        /// Expected answers, defaults to `answers/inputs.txt` in the workspace
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Store the answers from this run as the expected ones instead of checking them
+        #[arg(long)]
+        update: bool,
+    },
+    /// Draw a grid puzzle as a PPM or SVG image, optionally with numbered frames of each step
+    Render {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        input: PathBuf,
+        /// What to draw, e.g. `visibility` for day 8, defaults to the day's first view
+        #[arg(long)]
+        view: Option<String>,
+        /// Image to write, `.ppm` or `.svg`
+        #[arg(long)]
+        out: PathBuf,
+        /// Also write a frame per simulation step into this directory, in the same format
+        #[arg(long)]
+        frames: Option<PathBuf>,
+        /// Only keep every nth frame, and the last one
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        every: u64,
+    },
+    /// Rerun a day whenever its source or inputs change, showing how the answers changed
+    Watch {
+        #[arg(long)]
+        day: u8,
+        /// Inputs to run on, defaults to the day's puzzle input
+        #[arg(long, num_args = 1..)]
+        input: Vec<PathBuf>,
+        /// How often to check for changes, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Create a new day's crate and register it with the workspace and this runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Print a random but valid puzzle input for a day
+    Gen {
+        #[arg(long)]
+        day: u8,
+        /// Seed for the generator, the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Rough scale of the puzzle, e.g. elves for day 1 or the grid side for day 8
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    /// One JSON record per line, with `elapsed` in seconds
+    Json,
+}
+
+/// Outcome of solving one part of one input, as emitted by `--format json`
+#[derive(Serialize, Deserialize)]
+struct Record {
+    day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part: Option<u8>,
+    input: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    elapsed: Option<f64>,
+}
+
+impl Record {
+    fn emit(&self, format: Format) {
+        match (format, &self.answer, &self.error) {
+            (Format::Json, _, _) => println!("{}", serde_json::to_string(self).unwrap()),
+            (Format::Text, Some(answer), _) => print_answer(self.part.unwrap_or_default(), answer),
+            (Format::Text, None, error) => eprintln!("{}", error.as_deref().unwrap_or_default()),
+        }
+    }
+}
+
+fn print_answer(part: u8, answer: &str) {
+    // Multi-line answers (e.g. day 10's CRT) start on their own line so they stay aligned
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+/// Solves the requested parts of one input, returning whether all of them succeeded
+fn run_file(solver: &days::Day, part: Option<u8>, input_path: &PathBuf, format: Format) -> bool {
+    let name = input_name(input_path);
+    let record = |part, outcome: Result<String, String>, elapsed: Option<Duration>| {
+        let (answer, error) = match outcome {
+            Ok(answer) => (Some(answer), None),
+            Err(error) => (None, Some(error)),
+        };
+        let ok = error.is_none();
+        Record { day: solver.day, part, input: name.clone(), answer, error, elapsed: elapsed.map(|e| e.as_secs_f64()) }
+            .emit(format);
+        ok
+    };
+
+    let input = match read_input(input_path) {
+        Ok(input) => input,
+        Err(e) => return record(None, Err(format!("Could not read {}: {}", name, e)), None),
+    };
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => (1..=2).filter(|&part| solver.part(part).is_some()).collect(),
+    };
+    for part in parts {
+        let solve = match solver.part(part) {
+            Some(solve) => solve,
+            None => return record(Some(part), Err(format!("Day {} has no part {}", solver.day, part)), None),
+        };
+
+        let start = Instant::now();
+        let answer = solve(&input);
+        let elapsed = start.elapsed();
+        // Text output stops at the first error, just like the per-day binaries
+        let located = |e: ParseError| e.with_file(&name).to_string();
+        if !record(Some(part), answer.map_err(located), Some(elapsed)) {
+            return false;
+        }
+    }
+    true
+}
+
+fn run(day: u8, part: Option<u8>, input_paths: &[PathBuf], format: Format) -> Result<(), String> {
+    let solver = days::find(day).ok_or(format!("Day {} has no registered solver", day))?;
+
+    // Keep going after a bad file so one run can check a whole directory of inputs
+    let mut failures = 0;
+    for input_path in input_paths {
+        if input_paths.len() > 1 && format == Format::Text {
+            println!("==> {} <==", input_name(input_path));
+        }
+        if !run_file(solver, part, input_path, format) {
+            failures += 1;
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => Err(format!("{} of {} inputs failed", n, input_paths.len())),
+    }
+}
+
+/// Fits an answer on one table row, multi-line ones like day 10's CRT are just summarised
+fn table_cell(answer: &str) -> String {
+    match answer.lines().count() {
+        0 | 1 => answer.to_string(),
+        n => format!("<{} lines>", n),
+    }
+}
+
+fn all(answers_path: Option<&Path>, update: bool) -> Result<(), String> {
+    let root = aoc::workspace_root();
+    let answers_path = answers_path.map_or_else(|| root.join("answers/inputs.txt"), Path::to_path_buf);
+    let expected = match fs::read_to_string(&answers_path) {
+        Ok(contents) => answers::parse(&contents).map_err(|e| e.with_file(&answers_path).to_string())?,
+        Err(e) if update && e.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(format!("Could not read {}: {}", answers_path.display(), e)),
+    };
+
+    let mut rows = Vec::new();
+    let mut actual = Vec::new();
+    let mut failures = 0;
+    for day in days::DAYS {
+        let input = read_input(root.join(day.input));
+        for part in (1..=2).filter(|&part| day.part(part).is_some()) {
+            let solve = day.part(part).unwrap();
+            let start = Instant::now();
+            let outcome = match &input {
+                Ok(input) => solve(input).map_err(|e| e.with_file(day.input).to_string()),
+                Err(e) => Err(format!("Could not read {}: {}", day.input, e)),
+            };
+            let elapsed = start.elapsed();
+
+            let stored = expected.iter().find(|a| a.day == day.day && a.part == part && a.input == day.input);
+            let (answer, status) = match (&outcome, stored) {
+                (Err(e), _) => (String::new(), format!("error: {}", e)),
+                (Ok(answer), _) if update => (answer.clone(), "stored".to_string()),
+                (Ok(answer), None) => (answer.clone(), "no stored answer".to_string()),
+                (Ok(answer), Some(stored)) if stored.answer == *answer => (answer.clone(), "ok".to_string()),
+                (Ok(answer), Some(stored)) => (answer.clone(), format!("MISMATCH, expected {}", table_cell(&stored.answer))),
+            };
+            if outcome.is_err() || status.starts_with("MISMATCH") {
+                failures += 1;
+            }
+            if let Ok(answer) = outcome {
+                actual.push(Answer { day: day.day, part, input: day.input.to_string(), answer });
+            }
+            rows.push((day.day, part, table_cell(&answer), format!("{:.2?}", elapsed), status));
+        }
+    }
+
+    let width = rows.iter().map(|row| row.2.chars().count()).max().unwrap_or(0).max("answer".len());
+    println!("{:>3}  {:>4}  {:<width$}  {:>10}  status", "day", "part", "answer", "time");
+    for (day, part, answer, elapsed, status) in &rows {
+        println!("{:>3}  {:>4}  {:<width$}  {:>10}  {}", day, part, answer, elapsed, status);
+    }
+
+    if update {
+        let lines = actual.iter().map(|answer| answer.to_line() + "\n").collect::<String>();
+        fs::write(&answers_path, format!("# day part input answer\n{}", lines))
+            .map_err(|e| format!("Could not write {}: {}", answers_path.display(), e))?;
+        println!("Stored {} answers in {}", actual.len(), answers_path.display());
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => Err(format!("{} of {} answers failed", n, rows.len())),
+    }
+}
+
+fn render(day: u8, input_path: &PathBuf, view: Option<&str>, out: &Path, frames: Option<&Path>, every: u64) -> Result<(), String> {
+    let view = visuals::find(day, view)?;
+    let format = image::Format::from_path(out).ok_or(format!("{} is not a .ppm or .svg file", out.display()))?;
+    let input = read_input(input_path)
+        .map_err(|e| format!("Could not read {}: {}", input_name(input_path), e))?;
+    let picture = (view.draw)(&input).map_err(|e| e.with_file(input_name(input_path)).to_string())?;
+
+    if frames.is_some() && picture.frames.is_none() {
+        return Err(format!("Day {} {} is a still image, it has no frames", day, view.name));
+    }
+
+    picture.image.save(out).map_err(|e| format!("Could not write {}: {}", out.display(), e))?;
+    println!("Wrote {}", out.display());
+
+    if let (Some(dir), Some(steps)) = (frames, picture.frames) {
+        let write_err = |e: io::Error| format!("Could not write frames to {}: {}", dir.display(), e);
+        let mut frames = Frames::new(dir, format).map_err(write_err)?;
+        for frame in image::every_nth(steps, every as usize) {
+            frames.push(&frame).map_err(write_err)?;
+        }
+        println!("Wrote {} frames to {}", frames.count(), dir.display());
+    }
+    Ok(())
+}
+
+fn new(day: u8) -> Result<(), String> {
+    let root = aoc::workspace_root();
+    for path in scaffold::new_day(&root, day)? {
+        println!("Wrote {}", path.strip_prefix(&root).unwrap_or(&path).display());
+    }
+    Ok(())
+}
+
+fn gen(day: u8, seed: u64, size: usize) -> Result<(), String> {
+    let input = inputgen::generate(day, seed, size)
+        .ok_or(format!("Day {} has no input generator, try one of {:?}", day, inputgen::DAYS))?;
+    print!("{}", input);
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let target = match &cli.command {
+        Command::Run { day, .. } | Command::Render { day, .. } | Command::Watch { day, .. } => format!("day{}", day),
+        Command::All { .. } | Command::New { .. } => "aoc".to_string(),
+        Command::Gen { .. } => "inputgen".to_string(),
+    };
+    common::init_tracing(cli.verbose, &target);
+
+    let result = match &cli.command {
+        Command::Run { day, part, input, format } => run(*day, *part, input, *format),
+        Command::All { answers, update } => all(answers.as_deref(), *update),
+        Command::Render { day, input, view, out, frames, every } => {
+            render(*day, input, view.as_deref(), out, frames.as_deref(), *every)
+        }
+        Command::Watch { day, input, interval } => {
+            let inputs = match (input.is_empty(), days::find(*day)) {
+                (false, _) => input.iter().filter_map(|input| std::path::absolute(input).ok()).collect(),
+                (true, Some(solver)) => vec![aoc::workspace_root().join(solver.input)],
+                (true, None) => vec![],
+            };
+            watch::watch(*day, &inputs, Duration::from_millis(*interval))
+        }
+        Command::New { day } => new(*day),
+        Command::Gen { day, seed, size } => gen(*day, *seed, *size),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
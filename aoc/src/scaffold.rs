@@ -0,0 +1,101 @@
+//! `aoc new`: creates a day's crate and registers it everywhere the other days are
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = { path = "../common" }
+"#;
+
+const LIB_RS: &str = r#"use common::ParseError;
+
+pub fn solve_part1(_input: &str) -> Result<u64, ParseError> {
+    Ok(0)
+}
+
+pub fn solve_part2(_input: &str) -> Result<u64, ParseError> {
+    Ok(0)
+}
+"#;
+
+const MAIN_RS: &str = r#"use std::env;
+
+use common::{init_tracing, run_inputs, take_verbosity, ParseError};
+
+fn run(input: &str) -> Result<(), ParseError> {
+    println!("part 1: {}", day{day}::solve_part1(input)?);
+    println!("part 2: {}", day{day}::solve_part2(input)?);
+    Ok(())
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    init_tracing(take_verbosity(&mut args), "day{day}");
+    run_inputs(&args, run);
+}
+"#;
+
+/// Adds `line` after the last line of `path` that starts with `after`, ignoring indentation
+fn insert_after_last(path: &Path, after: &str, line: &str) -> Result<(), String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let last = lines
+        .iter()
+        .rposition(|l| l.trim_start().starts_with(after))
+        .ok_or(format!("Could not find a line starting with `{}` in {}", after, path.display()))?;
+    lines.insert(last + 1, line);
+    fs::write(path, lines.join("\n") + "\n").map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+/// Creates `day<day>` under the workspace `root` and registers it with the workspace, the runner
+/// and the regression answers, returning every file it created or changed
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let template = |text: &str| text.replace("{day}", &day.to_string());
+    let files = [
+        ("Cargo.toml", template(CARGO_TOML)),
+        ("src/lib.rs", LIB_RS.to_string()),
+        ("src/main.rs", template(MAIN_RS)),
+        ("test_input", String::new()),
+        ("input", String::new()),
+    ];
+
+    let mut touched = Vec::new();
+    fs::create_dir_all(dir.join("src")).map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+    for (file, contents) in files {
+        let path = dir.join(file);
+        fs::write(&path, contents).map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+        touched.push(path);
+    }
+
+    let registrations = [
+        ("Cargo.toml", "\"day", format!("    \"{}\",", name)),
+        ("aoc/Cargo.toml", "day", format!("{} = {{ path = \"../{}\" }}", name, name)),
+        (
+            "aoc/src/days.rs",
+            "Day { day:",
+            format!(
+                "    Day {{ day: {day}, input: \"{name}/input\", part1: solver!({name}::solve_part1), part2: Some(solver!({name}::solve_part2)) }},"
+            ),
+        ),
+        ("answers/fixtures.txt", "", format!("{} 1 {}/test_input 0\n{} 2 {}/test_input 0", day, name, day, name)),
+    ];
+    for (file, after, line) in registrations {
+        let path = root.join(file);
+        insert_after_last(&path, after, &line)?;
+        touched.push(path);
+    }
+    Ok(touched)
+}
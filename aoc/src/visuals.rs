@@ -0,0 +1,130 @@
+//! Pictures of the grid puzzles, for inputs too big to follow in a terminal
+
+use common::ParseError;
+use grid::image::{shade, Rgb, BLACK, RED, WHITE};
+use grid::Grid;
+
+const GROUND: Rgb = [30, 30, 30];
+const TRAIL: Rgb = [110, 110, 110];
+const LOW: Rgb = [20, 60, 20];
+const HIGH: Rgb = [190, 240, 130];
+
+/// A finished image, and for simulations the frames leading up to it
+pub struct Picture {
+    pub image: Grid<Rgb>,
+    /// Produced lazily, since a simulation can take thousands of steps
+    pub frames: Option<Box<dyn Iterator<Item = Grid<Rgb>>>>,
+}
+
+pub struct View {
+    pub day: u8,
+    pub name: &'static str,
+    pub draw: fn(&str) -> Result<Picture, ParseError>,
+}
+
+pub const VIEWS: &[View] = &[
+    View { day: 8, name: "heights", draw: tree_heights },
+    View { day: 8, name: "visibility", draw: visible_trees },
+    View { day: 9, name: "rope2", draw: |input| rope(input, 2) },
+    View { day: 9, name: "rope10", draw: |input| rope(input, 10) },
+    View { day: 10, name: "crt", draw: crt },
+    View { day: 12, name: "path", draw: heightmap_path },
+];
+
+/// The named view of a day, or its first one if no name is given
+pub fn find(day: u8, name: Option<&str>) -> Result<&'static View, String> {
+    let mut views = VIEWS.iter().filter(|view| view.day == day).peekable();
+    if views.peek().is_none() {
+        let days = VIEWS.iter().map(|view| view.day).collect::<std::collections::BTreeSet<_>>();
+        return Err(format!("Day {} has nothing to render, try one of {:?}", day, days));
+    }
+    let names = views.clone().map(|view| view.name).collect::<Vec<_>>();
+    match name {
+        None => Ok(views.next().unwrap()),
+        Some(name) => views
+            .find(|view| view.name == name)
+            .ok_or(format!("Day {} has no view {}, try one of {:?}", day, name, names)),
+    }
+}
+
+fn still(image: Grid<Rgb>) -> Picture {
+    Picture { image, frames: None }
+}
+
+fn tree_heights(input: &str) -> Result<Picture, ParseError> {
+    let heights = day8::tree_heights(input)?;
+    Ok(still(heights.map(|&h| shade(h as u32, 9, LOW, HIGH))))
+}
+
+fn visible_trees(input: &str) -> Result<Picture, ParseError> {
+    let visible = day8::visible_trees(input)?;
+    Ok(still(visible.map(|&v| if v { HIGH } else { GROUND })))
+}
+
+fn rope(input: &str, knots: usize) -> Result<Picture, ParseError> {
+    let steps = day9::rope_steps(input, knots)?;
+    let all = steps.iter().flatten();
+    let min_x = all.clone().map(|k| k.0).min().unwrap_or(0);
+    let max_x = all.clone().map(|k| k.0).max().unwrap_or(0);
+    let min_y = all.clone().map(|k| k.1).min().unwrap_or(0);
+    let max_y = all.map(|k| k.1).max().unwrap_or(0);
+    // Rows go down the image while y goes up
+    let to_cell = move |(x, y): (i64, i64)| ((x - min_x) as usize, (max_y - y) as usize);
+    let (width, height) = ((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize);
+
+    let draw = move |trail: &Grid<bool>, rope: &[(i64, i64)]| {
+        let mut image = trail.map(|&t| if t { TRAIL } else { GROUND });
+        for (i, &knot) in rope.iter().enumerate().rev() {
+            image[to_cell(knot)] = if i == 0 { RED } else { WHITE };
+        }
+        image
+    };
+
+    let mut trail = Grid::new_default(false, width, height);
+    for rope in &steps {
+        trail[to_cell(*rope.last().unwrap())] = true;
+    }
+    let image = draw(&trail, steps.last().map_or(&[][..], |rope| rope));
+
+    let mut trail = Grid::new_default(false, width, height);
+    let frames = steps.into_iter().map(move |rope| {
+        trail[to_cell(*rope.last().unwrap())] = true;
+        draw(&trail, &rope)
+    });
+    Ok(Picture { image, frames: Some(Box::new(frames)) })
+}
+
+fn crt(input: &str) -> Result<Picture, ParseError> {
+    let screen = day10::solve_part2(input)?;
+    let lit = Grid::parse(&screen, "a pixel", |c| Some(c == '#')).expect("the CRT is always 40x6");
+    let image = lit.map(|&on| if on { WHITE } else { BLACK });
+
+    // The beam draws one pixel per cycle, left to right and top to bottom
+    let frames = (1..=lit.len()).map(move |drawn| {
+        let mut frame = Grid::new_default(BLACK, lit.width(), lit.height());
+        for i in 0..drawn {
+            frame[i] = if lit[i] { WHITE } else { BLACK };
+        }
+        frame
+    });
+    Ok(Picture { image, frames: Some(Box::new(frames)) })
+}
+
+fn heightmap_path(input: &str) -> Result<Picture, ParseError> {
+    let (heights, path) = day12::shortest_path(input)?;
+    let map = heights.map(|&h| shade(h as u32, 25, LOW, HIGH));
+
+    let mut image = map.clone();
+    for &coord in &path {
+        image[coord] = RED;
+    }
+
+    let frames = (1..=path.len()).map(move |walked| {
+        let mut frame = map.clone();
+        for &coord in &path[..walked] {
+            frame[coord] = RED;
+        }
+        frame
+    });
+    Ok(Picture { image, frames: Some(Box::new(frames)) })
+}
@@ -0,0 +1,122 @@
+//! `aoc watch`: reruns a day whenever its source or inputs change
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::Record;
+
+type Stamps = Vec<(PathBuf, Option<SystemTime>)>;
+
+/// Last modified time of every file under `path`, skipping build output
+fn collect_stamps(path: &Path, stamps: &mut Stamps) {
+    if path.is_dir() {
+        if path.file_name().is_some_and(|name| name == "target") {
+            return;
+        }
+        let mut entries = fs::read_dir(path)
+            .map(|dir| dir.filter_map(|entry| entry.ok()).map(|entry| entry.path()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        entries.sort();
+        for entry in entries {
+            collect_stamps(&entry, stamps);
+        }
+    } else {
+        // A file that's missing or mid-save still counts, so it shows up as a change later
+        stamps.push((path.to_path_buf(), fs::metadata(path).and_then(|m| m.modified()).ok()));
+    }
+}
+
+fn changed<'a>(before: &'a Stamps, after: &'a Stamps) -> Vec<&'a Path> {
+    let before = before.iter().cloned().collect::<HashMap<_, _>>();
+    after
+        .iter()
+        .filter(|(path, stamp)| before.get(path) != Some(stamp))
+        .map(|(path, _)| path.as_path())
+        .collect()
+}
+
+/// Rebuilds and runs the day through cargo, so source edits are picked up, returning the
+/// outcome of each input and part
+fn run_once(root: &Path, day: u8, inputs: &[PathBuf]) -> Vec<Record> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let output = Command::new(cargo)
+        .current_dir(root)
+        .args(["run", "--quiet", "--release", "--package", "aoc", "--", "run", "--format", "json"])
+        .arg("--day")
+        .arg(day.to_string())
+        .arg("--input")
+        .args(inputs)
+        .stderr(Stdio::inherit())
+        .output();
+
+    match output {
+        Ok(output) => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect(),
+        Err(e) => {
+            eprintln!("Could not run cargo: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+fn outcome(record: &Record) -> String {
+    match (&record.answer, &record.error) {
+        (Some(answer), _) => answer.clone(),
+        (None, error) => format!("error: {}", error.as_deref().unwrap_or_default()),
+    }
+}
+
+/// Polls the day's crate and `inputs`, which must be absolute, every `interval`, printing how the answers changed after
+/// each rerun
+pub fn watch(day: u8, inputs: &[PathBuf], interval: Duration) -> Result<(), String> {
+    let root = aoc::workspace_root();
+    let crate_dir = root.join(format!("day{}", day));
+    if !crate_dir.is_dir() {
+        return Err(format!("{} does not exist", crate_dir.display()));
+    }
+    let mut stamps = Stamps::new();
+    let mut previous: HashMap<(String, Option<u8>), String> = HashMap::new();
+    loop {
+        let mut current = Stamps::new();
+        collect_stamps(&crate_dir, &mut current);
+        for input in inputs {
+            collect_stamps(input, &mut current);
+        }
+        // Inputs usually live in the crate directory too
+        current.sort();
+        current.dedup();
+
+        let changes = changed(&stamps, &current);
+        if !changes.is_empty() {
+            match changes.as_slice() {
+                _ if previous.is_empty() => println!("== running day {}", day),
+                [path] => println!("== {} changed, rerunning", path.strip_prefix(&root).unwrap_or(path).display()),
+                paths => println!("== {} files changed, rerunning", paths.len()),
+            }
+
+            for record in run_once(&root, day, inputs) {
+                let key = (record.input.clone(), record.part);
+                let now = outcome(&record);
+                let part = record.part.map_or("-".to_string(), |part| part.to_string());
+                let name = Path::new(&record.input);
+                let name = name.strip_prefix(&root).unwrap_or(name).display();
+                let elapsed = record.elapsed.map_or(String::new(), |e| format!(" in {:.2?}", Duration::from_secs_f64(e)));
+                match previous.get(&key) {
+                    Some(before) if *before == now => println!("{} part {}: {} (unchanged){}", name, part, now, elapsed),
+                    Some(before) => println!("{} part {}: {} (was {}){}", name, part, now, before, elapsed),
+                    None => println!("{} part {}: {}{}", name, part, now, elapsed),
+                }
+                previous.insert(key, now);
+            }
+            stamps = current;
+        }
+        thread::sleep(interval);
+    }
+}
@@ -0,0 +1,20 @@
+//! Every day's generated inputs are well formed, so every part solves them without an error.
+
+use aoc::days;
+
+#[test]
+fn generated_inputs_solve() {
+    for &day in inputgen::DAYS {
+        let solvers = days::find(day).unwrap_or_else(|| panic!("day {} has a generator but no solver", day));
+        for seed in 0..8 {
+            let input = inputgen::generate(day, seed, 12).unwrap();
+            for part in [1, 2] {
+                if let Some(solver) = solvers.part(part) {
+                    if let Err(e) = solver(&input) {
+                        panic!("day {} part {} fails on seed {}: {}\n{}", day, part, seed, e, input);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Runs every day against its example fixtures and compares with `answers/fixtures.txt`.
+//!
+//! After an intentional change in answers, regenerate the file with
+//! `UPDATE_ANSWERS=1 cargo test -p aoc --test regression`.
+
+use std::env;
+use std::fs;
+
+use aoc::answers::{self, Answer};
+use aoc::days;
+use common::read_input;
+
+fn solve(expected: &Answer) -> String {
+    let root = aoc::workspace_root();
+    let solver = days::find(expected.day)
+        .and_then(|day| day.part(expected.part))
+        .unwrap_or_else(|| panic!("day {} part {} has no solver", expected.day, expected.part));
+    let input = read_input(root.join(&expected.input))
+        .unwrap_or_else(|e| panic!("could not read {}: {}", expected.input, e));
+
+    match solver(&input) {
+        Ok(answer) => answer,
+        Err(e) => format!("error: {}", e.with_file(&expected.input)),
+    }
+}
+
+#[test]
+fn fixtures_match_checked_in_answers() {
+    let path = aoc::workspace_root().join("answers/fixtures.txt");
+    let contents = fs::read_to_string(&path).unwrap();
+    let expected = answers::parse(&contents).unwrap_or_else(|e| panic!("{}", e.with_file(&path)));
+
+    let actual = expected
+        .iter()
+        .map(|expected| Answer { answer: solve(expected), ..expected.clone() })
+        .collect::<Vec<_>>();
+
+    if env::var_os("UPDATE_ANSWERS").is_some() {
+        let lines = actual.iter().map(|answer| answer.to_line() + "\n").collect::<String>();
+        fs::write(&path, format!("# day part input answer\n{}", lines)).unwrap();
+        return;
+    }
+
+    let diff = answers::diff(&expected, &actual);
+    assert!(diff.is_empty(), "answers differ from {}:\n{}", path.display(), diff);
+}
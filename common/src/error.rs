@@ -0,0 +1,68 @@
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Malformed puzzle input, located by 1-based line and column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Error pointing at `token`, which must be a slice of `input`
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(offset <= input.len(), "token is not a slice of the input");
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        Self::new(line, column, expected, token)
+    }
+
+    /// Error for a token missing straight after `preceding`, a slice of `input`
+    pub fn after(input: &str, preceding: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &preceding[preceding.len()..], expected)
+    }
+
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}:{}: expected {}, found ", self.line, self.column, self.expected)?;
+        if self.found.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "`{}`", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, reporting where it sits if it is malformed
+pub fn parse_at<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::at(input, token, expected))
+}
@@ -0,0 +1,77 @@
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+use crate::ParseError;
+
+/// Input path that stands for stdin rather than a file
+pub const STDIN: &str = "-";
+
+/// Reads a whole puzzle input from `path`, or from stdin if it is `-`, and normalises it
+pub fn read_input(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref();
+    let input = if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        input
+    } else {
+        fs::read_to_string(path)?
+    };
+    Ok(normalize(&input))
+}
+
+/// Opens a puzzle input for reading line by line, from stdin if `path` is `-`
+///
+/// Nothing is normalised here; callers streaming large inputs trim each line themselves.
+pub fn open_input(path: impl AsRef<Path>) -> io::Result<Box<dyn BufRead>> {
+    let path = path.as_ref();
+    if path == Path::new(STDIN) {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(fs::File::open(path)?)))
+    }
+}
+
+/// Undoes what editors on other machines do to an input, so the parsers only ever see plain
+/// Unix text: drops a byte order mark, turns `\r\n` and lone `\r` line endings into `\n`,
+/// strips whitespace from the end of every line and leaves exactly one newline at the end
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = input
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Width and height of a map with one cell per character, checking every row is as wide as
+/// the first
+pub fn grid_size(input: &str) -> Result<(usize, usize), ParseError> {
+    let rows = input.lines().collect::<Vec<&str>>();
+    let first_row = rows.first().ok_or_else(|| ParseError::after(input, input, "a row of the grid"))?;
+    let width = first_row.chars().count();
+    if width == 0 {
+        return Err(ParseError::at(input, first_row, "a row of the grid"));
+    }
+    if let Some(row) = rows.iter().find(|row| row.chars().count() != width) {
+        return Err(ParseError::at(input, row, format!("a row of {} cells", width)));
+    }
+    Ok((width, rows.len()))
+}
+
+/// Name to show for an input in headers and error messages
+pub fn input_name(path: impl AsRef<Path>) -> String {
+    let path = path.as_ref();
+    if path == Path::new(STDIN) {
+        "<stdin>".to_string()
+    } else {
+        path.display().to_string()
+    }
+}
@@ -0,0 +1,11 @@
+//! Helpers shared by every day's solver
+
+mod error;
+mod input;
+mod logging;
+mod runner;
+
+pub use error::{parse_at, ParseError};
+pub use input::{grid_size, input_name, normalize, open_input, read_input, STDIN};
+pub use logging::{init_tracing, take_verbosity};
+pub use runner::{run_inputs, run_paths};
@@ -0,0 +1,31 @@
+use std::io::{self, IsTerminal};
+
+use tracing_subscriber::EnvFilter;
+
+/// Sends tracing events from the `target` day to stderr, leaving stdout to the answers
+///
+/// Verbosity 1 (`-v`) shows debug events and 2 (`-vv`) every trace event. `RUST_LOG` overrides
+/// this, e.g. `RUST_LOG=day11=debug,day13=trace`.
+pub fn init_tracing(verbosity: u8, target: &str) {
+    let level = match verbosity {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(format!("warn,{}={}", target, level)));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
+
+/// Removes `-v` style flags from a day binary's arguments, returning the number of `v`s
+pub fn take_verbosity(args: &mut Vec<String>) -> u8 {
+    let is_flag = |arg: &String| arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v');
+    let verbosity = args.iter().filter(|arg| is_flag(arg)).map(|arg| arg.len() - 1).sum::<usize>();
+    args.retain(|arg| !is_flag(arg));
+    verbosity.min(u8::MAX as usize) as u8
+}
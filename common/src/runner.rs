@@ -0,0 +1,37 @@
+use std::process;
+
+use crate::{input_name, read_input, ParseError};
+
+/// Runs a day binary's `run` over every input named in `args`, the program name first
+///
+/// Each input is read with `read_input`, and parse errors are reported against its file.
+/// Failures don't stop the remaining inputs; see `run_paths`.
+pub fn run_inputs(args: &[String], mut run: impl FnMut(&str) -> Result<(), ParseError>) {
+    run_paths(args, |path| match read_input(path) {
+        Ok(input) => run(&input).map_err(|e| e.with_file(input_name(path)).to_string()),
+        Err(e) => Err(format!("Could not read {}: {}", input_name(path), e)),
+    })
+}
+
+/// Runs `run` on every input path in `args`, for binaries that read their inputs themselves
+///
+/// Each input gets a `==> name <==` header when there are several. Errors are printed to
+/// stderr as they happen, and the process exits with status 1 at the end if any input failed.
+pub fn run_paths(args: &[String], mut run: impl FnMut(&str) -> Result<(), String>) {
+    assert!(args.len() >= 2, "Need to provide at least one input file (or - for stdin) as an argument");
+
+    let mut failed = false;
+    for path in &args[1..] {
+        if args.len() > 2 {
+            println!("==> {} <==", input_name(path));
+        }
+        if let Err(e) = run(path) {
+            eprintln!("{}", e);
+            failed = true;
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,298 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::io::{self, BufRead};
+
+use common::ParseError;
+use tracing::warn;
+
+mod stats;
+
+pub use stats::CalorieStats;
+
+/// How to treat lines that are neither a calorie count nor blank
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Fail on the first such line
+    Strict,
+    /// Skip them with a warning
+    Lenient,
+}
+
+/// One elf's pack: its 1-based position in the input, how many items it holds, their total and
+/// the heaviest of them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub items: usize,
+    pub total: u64,
+    pub largest: u64,
+}
+
+impl Elf {
+    fn new(index: usize) -> Self {
+        Elf { index, items: 0, total: 0, largest: 0 }
+    }
+}
+
+/// Groups lines into elves one line at a time, so the input never has to be held in memory
+///
+/// Any run of blank lines is a single separator, and only once the elf before it has an item
+/// and another item follows, so blank lines at the start or end never make empty elves.
+struct Packer {
+    mode: Mode,
+    line: usize,
+    elf: Elf,
+    separated: bool,
+}
+
+impl Packer {
+    fn new(mode: Mode) -> Self {
+        Packer { mode, line: 0, elf: Elf::new(1), separated: false }
+    }
+
+    /// Takes the next line, handing any elves it finishes to `done`
+    fn push(&mut self, line: &str, done: &mut impl FnMut(Elf)) -> Result<(), ParseError> {
+        self.line += 1;
+        if line.is_empty() {
+            self.separated = self.elf.items > 0;
+            return Ok(());
+        }
+        let calories = match line.parse::<u64>() {
+            Ok(calories) => calories,
+            Err(_) => {
+                let e = ParseError::new(self.line, 1, "calories or a blank line", line);
+                if self.mode == Mode::Strict {
+                    return Err(e);
+                }
+                warn!("skipping {}", e);
+                return Ok(());
+            }
+        };
+        if self.separated {
+            let next = Elf::new(self.elf.index + 1);
+            done(std::mem::replace(&mut self.elf, next));
+            self.separated = false;
+        }
+        self.elf.items += 1;
+        self.elf.largest = self.elf.largest.max(calories);
+        self.elf.total = self.elf.total.checked_add(calories)
+            .ok_or_else(|| ParseError::new(self.line, 1, "calories that keep the elf's total within 64 bits", line))?;
+        Ok(())
+    }
+
+    /// Hands over the last elf, failing if there were no elves at all
+    fn finish(self, done: &mut impl FnMut(Elf)) -> Result<(), ParseError> {
+        if self.elf.items == 0 {
+            return Err(ParseError::new(self.line + 1, 1, "at least one calorie count", ""));
+        }
+        done(self.elf);
+        Ok(())
+    }
+}
+
+/// Orders elves by total, putting the earlier elf first on equal totals
+#[derive(PartialEq, Eq)]
+struct ByTotal(Elf);
+
+impl Ord for ByTotal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total.cmp(&other.0.total).then(other.0.index.cmp(&self.0.index))
+    }
+}
+
+impl PartialOrd for ByTotal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Keeps the `k` heaviest elves seen so far in a min-heap, so the lightest is the one to drop
+struct Heaviest {
+    k: usize,
+    heap: BinaryHeap<Reverse<ByTotal>>,
+}
+
+impl Heaviest {
+    fn new(k: usize) -> Self {
+        Heaviest { k, heap: BinaryHeap::new() }
+    }
+
+    /// Offers an elf; on equal totals the later elf is the one dropped
+    fn push(&mut self, elf: Elf) {
+        if self.k == 0 {
+            return;
+        }
+        let key = Reverse(ByTotal(elf));
+        if self.heap.len() < self.k {
+            self.heap.push(key);
+        } else if key < *self.heap.peek().unwrap() {
+            self.heap.pop();
+            self.heap.push(key);
+        }
+    }
+
+    /// The kept elves, heaviest first
+    fn into_ranked(self) -> Vec<Elf> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(ByTotal(elf))| elf)
+            .collect()
+    }
+}
+
+/// Every elf in input order; only blank lines separate elves, and there must be at least one
+pub fn elves(input: &str, mode: Mode) -> Result<Vec<Elf>, ParseError> {
+    let mut elves = vec![];
+    let mut packer = Packer::new(mode);
+    for line in input.lines() {
+        packer.push(line, &mut |elf| elves.push(elf))?;
+    }
+    packer.finish(&mut |elf| elves.push(elf))?;
+    Ok(elves)
+}
+
+/// Every elf, heaviest first; elves carrying the same total keep their input order
+pub fn ranked(input: &str, mode: Mode) -> Result<Vec<Elf>, ParseError> {
+    top(input, usize::MAX, mode)
+}
+
+/// The `n` heaviest elves, heaviest first
+pub fn top(input: &str, n: usize, mode: Mode) -> Result<Vec<Elf>, ParseError> {
+    let mut heaviest = Heaviest::new(n);
+    let mut packer = Packer::new(mode);
+    for line in input.lines() {
+        packer.push(line, &mut |elf| heaviest.push(elf))?;
+    }
+    packer.finish(&mut |elf| heaviest.push(elf))?;
+    Ok(heaviest.into_ranked())
+}
+
+/// Hands each elf read line by line from `reader` to `done`
+///
+/// Each line is trimmed as it arrives the way `common::normalize` would, and a `ParseError`
+/// comes back as an `InvalidData` error wrapping it.
+fn stream(reader: impl BufRead, mode: Mode, mut done: impl FnMut(Elf)) -> io::Result<()> {
+    let mut packer = Packer::new(mode);
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = if i == 0 { line.strip_prefix('\u{feff}').unwrap_or(&line) } else { &line };
+        packer
+            .push(line.trim_end(), &mut done)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    }
+    packer.finish(&mut done).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// The `k` heaviest elves read line by line from `reader`, heaviest first
+///
+/// Only the current elf and the top `k` are kept, so the input can be far larger than memory.
+pub fn top_k(reader: impl BufRead, k: usize, mode: Mode) -> io::Result<Vec<Elf>> {
+    let mut heaviest = Heaviest::new(k);
+    stream(reader, mode, |elf| heaviest.push(elf))?;
+    Ok(heaviest.into_ranked())
+}
+
+/// Every elf read line by line from `reader`, in input order
+pub fn read_elves(reader: impl BufRead, mode: Mode) -> io::Result<Vec<Elf>> {
+    let mut elves = vec![];
+    stream(reader, mode, |elf| elves.push(elf))?;
+    Ok(elves)
+}
+
+/// Lays out elves as a table of rank, elf index, item count and total
+pub fn render_table(elves: &[Elf]) -> String {
+    let mut table = format!("{:>4}  {:>5}  {:>5}  {:>8}\n", "rank", "elf", "items", "calories");
+    for (rank, elf) in elves.iter().enumerate() {
+        table += &format!("{:>4}  {:>5}  {:>5}  {:>8}\n", rank + 1, elf.index, elf.items, elf.total);
+    }
+    table
+}
+
+/// Sum of the elves' totals, widened as a few 64-bit totals can already overflow 64 bits
+pub fn sum_totals(elves: &[Elf]) -> u128 {
+    elves.iter().map(|elf| elf.total as u128).sum()
+}
+
+pub fn solve_part1(input: &str) -> Result<u64, ParseError> {
+    Ok(top(input, 1, Mode::Strict)?[0].total)
+}
+
+pub fn solve_part2(input: &str) -> Result<u128, ParseError> {
+    Ok(sum_totals(&top(input, 3, Mode::Strict)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elf(index: usize, items: usize, total: u64, largest: u64) -> Elf {
+        Elf { index, items, total, largest }
+    }
+
+    #[test]
+    fn runs_of_blank_lines_separate_elves_once() {
+        let elves = elves("\n\n100\n\n\n200\n50\n\n", Mode::Strict).unwrap();
+        assert_eq!(elves, vec![elf(1, 1, 100, 100), elf(2, 2, 250, 200)]);
+    }
+
+    #[test]
+    fn top_reports_input_positions() {
+        let input = include_str!("../test_input.txt");
+        let top = top(input, 2, Mode::Strict).unwrap();
+        assert_eq!(top, vec![elf(4, 3, 24000, 9000), elf(3, 2, 11000, 6000)]);
+        assert_eq!(render_table(&top).lines().nth(1).unwrap().split_whitespace().collect::<Vec<_>>(), ["1", "4", "3", "24000"]);
+    }
+
+    #[test]
+    fn no_elves_is_an_error() {
+        for input in ["", "\n\n\n"] {
+            let e = elves(input, Mode::Strict).unwrap_err();
+            assert_eq!(e.expected, "at least one calorie count");
+            assert!(solve_part1(input).is_err());
+        }
+        assert!(top_k("oops\n".as_bytes(), 3, Mode::Lenient).is_err());
+    }
+
+    #[test]
+    fn equal_totals_keep_the_earlier_elf() {
+        let input = "5\n\n9\n\n5\n\n7\n\n9\n\n5\n";
+        let indices = |elves: Vec<Elf>| elves.iter().map(|elf| elf.index).collect::<Vec<usize>>();
+        assert_eq!(indices(top(input, 1, Mode::Strict).unwrap()), [2]);
+        assert_eq!(indices(top(input, 3, Mode::Strict).unwrap()), [2, 5, 4]);
+        assert_eq!(indices(top(input, 4, Mode::Strict).unwrap()), [2, 5, 4, 1]);
+        assert_eq!(indices(ranked(input, Mode::Strict).unwrap()), [2, 5, 4, 1, 3, 6]);
+        assert_eq!(indices(top_k(input.as_bytes(), 4, Mode::Strict).unwrap()), [2, 5, 4, 1]);
+    }
+
+    #[test]
+    fn top_zero_keeps_nothing() {
+        assert!(top("1\n\n2\n", 0, Mode::Strict).unwrap().is_empty());
+        assert!(top_k("1\n\n2\n".as_bytes(), 0, Mode::Strict).unwrap().is_empty());
+    }
+
+    #[test]
+    fn streaming_matches_reading_whole() {
+        let input = include_str!("../test_input.txt");
+        let crlf = include_bytes!("../test_input_crlf.txt");
+        assert_eq!(top_k(&crlf[..], 3, Mode::Strict).unwrap(), top(input, 3, Mode::Strict).unwrap());
+        assert_eq!(read_elves(input.as_bytes(), Mode::Strict).unwrap(), elves(input, Mode::Strict).unwrap());
+    }
+
+    #[test]
+    fn totals_past_u64_are_an_error() {
+        let input = format!("{}\n1\n", u64::MAX);
+        let e = elves(&input, Mode::Strict).unwrap_err();
+        assert_eq!((e.line, e.found.as_str()), (2, "1"));
+        assert_eq!(top_k(input.as_bytes(), 1, Mode::Lenient).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(elves(&format!("{}\n\n1\n", u64::MAX), Mode::Strict).unwrap()[0].total, u64::MAX);
+    }
+
+    #[test]
+    fn sums_of_totals_past_u64_are_widened() {
+        let input = format!("{}\n\n{}\n\n2\n", u64::MAX, u64::MAX);
+        assert_eq!(solve_part1(&input), Ok(u64::MAX));
+        assert_eq!(solve_part2(&input), Ok(2 * u64::MAX as u128 + 2));
+        assert_eq!(sum_totals(&top_k(input.as_bytes(), 2, Mode::Strict).unwrap()), 2 * u64::MAX as u128);
+    }
+}
@@ -1,23 +1,93 @@
 use std::env;
-use std::fs;
+use std::io::{self, BufRead};
+use std::process;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    assert_eq!(args.len(), 2, "Need to provide an input file as a second argument. \
-                               Number of arguments is not 2");
+use common::{init_tracing, input_name, open_input, run_paths, take_verbosity, ParseError};
+use day1::Mode;
+
+/// What to print besides the two answers
+enum Report {
+    Answers,
+    Top(usize),
+    Table,
+    Stats,
+}
+
+/// Streams the input rather than reading it whole, so generated inputs of any size fit
+fn run(reader: impl BufRead, report: &Report, mode: Mode) -> io::Result<()> {
+    match report {
+        Report::Answers => {
+            let elves = day1::top_k(reader, 3, mode)?;
+            println!("Max cals: {}", elves[0].total);
+            println!("Top 3 sum: {}", day1::sum_totals(&elves));
+        }
+        Report::Top(n) => {
+            let elves = day1::top_k(reader, *n, mode)?;
+            print!("{}", day1::render_table(&elves));
+            println!("Top {} sum: {}", elves.len(), day1::sum_totals(&elves));
+        }
+        Report::Table => print!("{}", day1::render_table(&day1::top_k(reader, usize::MAX, mode)?)),
+        Report::Stats => print!("{}", day1::CalorieStats::new(&day1::read_elves(reader, mode)?)),
+    }
+    Ok(())
+}
+
+/// Puts the file name in front of a parse error's position, or says the file couldn't be read
+fn describe(e: io::Error, path: &str) -> String {
+    if e.kind() == io::ErrorKind::InvalidData && e.get_ref().is_some_and(|inner| inner.is::<ParseError>()) {
+        let e = e.into_inner().unwrap().downcast::<ParseError>().unwrap();
+        e.with_file(input_name(path)).to_string()
+    } else {
+        format!("Could not read {}: {}", input_name(path), e)
+    }
+}
 
-    let mut sacks = fs::read_to_string(&args[1])
-        .unwrap()
-        .lines()
-        .fold(vec![0], |mut acc, line| {
-            match line.parse::<u32>() {
-                Err(_) => acc.push(0),
-                Ok(calories) => *acc.last_mut().unwrap() += calories,
+/// Removes `--lenient` from the arguments
+fn take_mode(args: &mut Vec<String>) -> Mode {
+    let before = args.len();
+    args.retain(|arg| arg != "--lenient");
+    if args.len() < before { Mode::Lenient } else { Mode::Strict }
+}
+
+/// Removes `--top N`, `--table` and `--stats` from the arguments
+fn take_report(args: &mut Vec<String>) -> Result<Report, String> {
+    let mut report = Report::Answers;
+    let mut i = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--table" => {
+                args.remove(i);
+                report = Report::Table;
+            }
+            "--stats" => {
+                args.remove(i);
+                report = Report::Stats;
             }
-            return acc;
-        });
-    sacks.sort();
+            "--top" => {
+                args.remove(i);
+                if i == args.len() {
+                    return Err("--top needs a number of elves".to_string());
+                }
+                let n = args.remove(i);
+                report = Report::Top(n.parse().map_err(|_| format!("--top needs a number of elves, not {}", n))?);
+            }
+            _ => i += 1,
+        }
+    }
+    Ok(report)
+}
 
-    println!("Max cals: {}", sacks.last().unwrap());
-    println!("Top 3 sum: {}", sacks.iter().rev().take(3).sum::<u32>())
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    init_tracing(take_verbosity(&mut args), "day1");
+    let report = take_report(&mut args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    let mode = take_mode(&mut args);
+    run_paths(&args, |path| {
+        open_input(path)
+            .and_then(|reader| run(reader, &report, mode))
+            .map_err(|e| describe(e, path))
+    });
 }
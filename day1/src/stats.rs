@@ -0,0 +1,182 @@
+//! Descriptive statistics over the elves' calorie totals
+
+use std::fmt;
+
+use crate::Elf;
+
+/// Percentiles reported by `CalorieStats`
+const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Summary of how calories are spread across the elves
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalorieStats {
+    /// Number of elves
+    pub count: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// Elf total at each of the 10th, 25th, 75th, 90th and 99th percentiles, by nearest rank
+    pub percentiles: Vec<(u8, u64)>,
+    /// Heaviest single item any elf carries
+    pub largest_item: u64,
+    /// Every elf's total, lightest first
+    totals: Vec<u64>,
+}
+
+impl CalorieStats {
+    pub fn new(elves: &[Elf]) -> Self {
+        let mut totals = elves.iter().map(|elf| elf.total).collect::<Vec<u64>>();
+        totals.sort();
+        let count = totals.len();
+        let mean = match count {
+            0 => 0.0,
+            _ => totals.iter().map(|&total| total as u128).sum::<u128>() as f64 / count as f64,
+        };
+        let median = match count {
+            0 => 0.0,
+            _ if count % 2 == 1 => totals[count / 2] as f64,
+            _ => (totals[count / 2 - 1] as f64 + totals[count / 2] as f64) / 2.0,
+        };
+        let largest_item = elves.iter().map(|elf| elf.largest).max().unwrap_or(0);
+        let mut stats = CalorieStats { count, mean, median, percentiles: vec![], largest_item, totals };
+        stats.percentiles = PERCENTILES.iter().map(|&p| (p, stats.percentile(p as f64))).collect();
+        stats
+    }
+
+    /// Smallest elf total that at least `p` percent of the elves carry no more than
+    pub fn percentile(&self, p: f64) -> u64 {
+        if self.totals.is_empty() {
+            return 0;
+        }
+        let rank = (p / 100.0 * self.count as f64).ceil() as usize;
+        self.totals[rank.clamp(1, self.count) - 1]
+    }
+
+    /// Bar chart of how many elves fall into each of `bins` equal ranges of totals, with the
+    /// longest bar `width` characters long
+    pub fn histogram(&self, bins: usize, width: usize) -> String {
+        let (Some(&min), Some(&max)) = (self.totals.first(), self.totals.last()) else {
+            return String::new();
+        };
+        let bins = bins.max(1) as u64;
+        let size = ((max - min) / bins + 1).max(1);
+        let mut counts = vec![0usize; ((max - min) / size + 1) as usize];
+        for &total in &self.totals {
+            counts[((total - min) / size) as usize] += 1;
+        }
+
+        let tallest = *counts.iter().max().unwrap();
+        let mut chart = String::new();
+        for (i, &count) in counts.iter().enumerate() {
+            let low = min + i as u64 * size;
+            let bar = "#".repeat((count * width).div_ceil(tallest));
+            let high = low.saturating_add(size - 1).min(max);
+            chart += &format!("{:>8} - {:>8} | {:<width$} {}\n", low, high, bar, count, width = width);
+        }
+        chart
+    }
+}
+
+impl fmt::Display for CalorieStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elves: {}", self.count)?;
+        writeln!(f, "Mean: {:.1}", self.mean)?;
+        writeln!(f, "Median: {}", self.median)?;
+        for (p, total) in &self.percentiles {
+            writeln!(f, "{}th percentile: {}", p, total)?;
+        }
+        writeln!(f, "Largest item: {}", self.largest_item)?;
+        write!(f, "{}", self.histogram(10, 40))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(totals: &[u64]) -> CalorieStats {
+        let elves = totals
+            .iter()
+            .enumerate()
+            .map(|(i, &total)| Elf { index: i + 1, items: 1, total, largest: total })
+            .collect::<Vec<Elf>>();
+        CalorieStats::new(&elves)
+    }
+
+    /// Each histogram row's range and count, leaving out the bar
+    fn bins(stats: &CalorieStats, bins: usize) -> Vec<(u64, u64, usize)> {
+        stats
+            .histogram(bins, 4)
+            .lines()
+            .map(|row| {
+                let numbers = row.split(|c: char| !c.is_ascii_digit()).filter(|n| !n.is_empty()).collect::<Vec<_>>();
+                (numbers[0].parse().unwrap(), numbers[1].parse().unwrap(), numbers[2].parse().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn odd_count() {
+        let stats = stats(&[3, 1, 2]);
+        assert_eq!((stats.count, stats.mean, stats.median), (3, 2.0, 2.0));
+        assert_eq!(stats.percentiles, vec![(10, 1), (25, 1), (75, 3), (90, 3), (99, 3)]);
+        assert_eq!(stats.largest_item, 3);
+    }
+
+    #[test]
+    fn even_count() {
+        let stats = stats(&[10, 40, 20, 30]);
+        assert_eq!((stats.count, stats.mean, stats.median), (4, 25.0, 25.0));
+        assert_eq!(stats.percentiles, vec![(10, 10), (25, 10), (75, 30), (90, 40), (99, 40)]);
+        assert_eq!((stats.percentile(0.0), stats.percentile(50.0), stats.percentile(100.0)), (10, 20, 40));
+    }
+
+    #[test]
+    fn one_elf() {
+        let stats = stats(&[7]);
+        assert_eq!((stats.count, stats.mean, stats.median), (1, 7.0, 7.0));
+        assert!(stats.percentiles.iter().all(|&(_, total)| total == 7));
+        assert_eq!(bins(&stats, 10), vec![(7, 7, 1)]);
+    }
+
+    #[test]
+    fn all_totals_equal() {
+        let stats = stats(&[5, 5, 5]);
+        assert_eq!((stats.mean, stats.median, stats.percentile(99.0)), (5.0, 5.0, 5));
+        assert_eq!(bins(&stats, 10), vec![(5, 5, 3)]);
+    }
+
+    #[test]
+    fn fewer_distinct_totals_than_bins() {
+        let stats = stats(&[10, 11, 12, 12]);
+        assert_eq!(bins(&stats, 10), vec![(10, 10, 1), (11, 11, 1), (12, 12, 2)]);
+        let bars = stats.histogram(10, 4).lines().map(|row| row.matches('#').count()).collect::<Vec<_>>();
+        assert_eq!(bars, [2, 2, 4]);
+    }
+
+    #[test]
+    fn even_spread_fills_every_bin() {
+        let stats = stats(&(1..=100).collect::<Vec<u64>>());
+        assert_eq!(stats.median, 50.5);
+        assert_eq!(stats.percentiles, vec![(10, 10), (25, 25), (75, 75), (90, 90), (99, 99)]);
+        let bins = bins(&stats, 10);
+        assert_eq!(bins.len(), 10);
+        assert_eq!(bins[0], (1, 10, 10));
+        assert_eq!(bins[9], (91, 100, 10));
+    }
+
+    #[test]
+    fn totals_spanning_u64() {
+        let rows = bins(&stats(&[u64::MAX, 0]), 10);
+        assert_eq!(rows.len(), 10);
+        assert_eq!((rows[0].0, rows[0].2), (0, 1));
+        assert_eq!(rows[9], (9 * (u64::MAX / 10 + 1), u64::MAX, 1));
+        assert_eq!(bins(&stats(&[u64::MAX - 1, u64::MAX]), 1), vec![(u64::MAX - 1, u64::MAX, 2)]);
+    }
+
+    #[test]
+    fn no_elves() {
+        let stats = stats(&[]);
+        assert_eq!((stats.count, stats.percentile(50.0)), (0, 0));
+        assert_eq!(stats.histogram(10, 4), "");
+    }
+}
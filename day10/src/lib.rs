@@ -0,0 +1,88 @@
+use std::collections::VecDeque;
+use common::{parse_at, ParseError};
+use tracing::{debug, trace};
+
+#[derive(Debug)]
+struct State {
+    x: i64,
+    add_x_buffer: VecDeque<i64>,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            x: 1,
+            add_x_buffer: VecDeque::new(),
+        }
+    }
+}
+
+fn run_program(input: &str) -> Result<(Vec<i64>, Vec<Vec<char>>), ParseError> {
+    let mut state = State::new();
+    let mut cycle: i64 = 0;
+    let mut lines = input.lines().peekable();
+    let mut signal_strengths: Vec<i64> = Vec::new();
+
+    let mut crt: Vec<Vec<char>> = vec![vec!['.'; 40]; 6];
+
+    loop {
+        let row = (cycle / 40) as usize;
+        let col = (cycle % 40) as usize;
+        // Programs longer than a frame keep running, there's just nowhere left to draw
+        if row < crt.len() {
+            crt[row][col] = if (state.x - 1..=state.x + 1).contains(&(col as i64)) {
+                '#'
+            } else {
+                '.'
+            };
+        }
+
+        cycle += 1;
+
+        if cycle == 20 || (cycle - 20) % 40 == 0 {
+            signal_strengths.push(state.x * cycle);
+        }
+
+        if let Some(line) = lines.next() {
+            trace!("cycle {}: {}", cycle, line);
+            let mut parts = line.split(' ');
+            match parts.next().unwrap() {
+                "addx" => {
+                    let value = parts
+                        .next()
+                        .ok_or_else(|| ParseError::after(input, line, "a space then a value to add"))?;
+                    state.add_x_buffer.push_back(0);
+                    state
+                        .add_x_buffer
+                        .push_back(parse_at(input, value, "an integer value")?)
+                }
+                "noop" => state.add_x_buffer.push_back(0),
+                op => return Err(ParseError::at(input, op, "`addx` or `noop`")),
+            }
+        }
+
+        if let Some(add_x) = state.add_x_buffer.pop_front() {
+            state.x += add_x
+        }
+
+        if state.add_x_buffer.is_empty() && lines.peek().is_none() {
+            break;
+        }
+    }
+    Ok((signal_strengths, crt))
+}
+
+pub fn solve_part1(input: &str) -> Result<i64, ParseError> {
+    let (signal_strengths, _) = run_program(input)?;
+    debug!("signal strengths: {:?}", signal_strengths);
+    Ok(signal_strengths.iter().sum::<i64>())
+}
+
+/// The CRT image, one row of pixels per line
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    let (_, crt) = run_program(input)?;
+    Ok(crt.iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
@@ -0,0 +1,210 @@
+//! Monkeys that keep exact worry levels with GMP big integers, as the first attempts at part 2 did
+//!
+//! Only built with the `bigint` feature, since GMP needs a C toolchain. The puzzle itself is
+//! solved by `ModMonke` with `u128` worry levels, kept exact while they are divided by 3 and
+//! modulo the divisors' common multiple when they aren't.
+
+use std::collections::VecDeque;
+use std::fmt;
+use rug::Integer;
+use rug::Complete;
+use rand::prelude::*;
+use tracing::{debug, trace};
+
+use super::{MonkeNotes, Op, Operand};
+
+pub struct Monke {
+    items: VecDeque<Integer>,
+    operation: Box<dyn Fn(&Integer) -> Integer>,
+    throws_to: Box<dyn Fn(&Integer) -> usize>,
+    items_handled_count: u64
+}
+
+impl Monke {
+    fn new(notes: &MonkeNotes, worry_div: u64) -> Self {
+        let items = notes.items.iter().map(|&num| Integer::from(num)).collect();
+
+        let operation: Box<dyn Fn(&Integer) -> Integer> = match (notes.op, notes.rhs) {
+            (Op::Mul, Operand::Num(rhs)) => Box::new(move |item| (item * rhs).complete()/worry_div),
+            (Op::Mul, Operand::Old) => Box::new(move |item| (item * item).complete()/worry_div),
+            (Op::Add, Operand::Num(rhs)) => Box::new(move |item| (item + rhs).complete()/worry_div),
+            (Op::Add, Operand::Old) => Box::new(move |item| (item + item).complete()/worry_div),
+        };
+        
+        let throws_to: Box<dyn Fn(&Integer) -> usize> = {
+            let (division, true_monke, false_monke) = (notes.divisor, notes.true_monke, notes.false_monke);
+            trace!("if div by {} throw to {} else {}", division, true_monke, false_monke);
+            Box::new(move |item| if (item % division).complete() == 0 { true_monke } else { false_monke })
+        };
+
+        Self {
+            items,
+            operation,
+            throws_to,
+            items_handled_count: 0
+        }
+
+    }
+}
+
+impl fmt::Debug for Monke {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Monke {{ items: {:?}, items_handled_count: {}}}", self.items, self.items_handled_count)
+    }
+}
+
+fn factorise_to_primes(n: &Integer) -> Vec<Integer> {
+    trace!("factorising: {}", n);
+    let mut primes = Vec::new();
+    let mut cur_prime = n.clone();
+    loop {
+        let new_prime = pollard_rho(&cur_prime);
+        cur_prime /= &new_prime;
+        // Pollard's rho finds a factor, not necessarily a prime one
+        if new_prime == 1 || new_prime.is_probably_prime(30) != rug::integer::IsPrime::No {
+            primes.push(new_prime);
+        } else {
+            primes.extend(factorise_to_primes(&new_prime));
+        }
+        if cur_prime == 1 {
+            break;
+        }
+    }
+    primes
+}
+
+fn pollard_rho(n: &Integer) -> Integer {
+    if *n == 1 {
+        return Integer::from(1);
+    }
+    if n.mod_u(2) == Integer::ZERO {
+        return Integer::from(2);
+    }
+    if n.is_probably_prime(30) == rug::integer::IsPrime::Yes {
+        return n.clone()
+    }
+    
+    let mut rng = rand::thread_rng();
+    
+    let mut x: Integer = Integer::from(rng.gen::<u64>() + 2);
+    let mut y = x.clone();
+
+    let c: Integer = Integer::from(rng.gen::<u64>() + 1);
+    let mut d = Integer::from(1);
+
+    let two = Integer::from(2);
+
+    while d == 1 {
+        x = (x.pow_mod(&two, n).unwrap() + &c + n) % n;
+        y = (y.pow_mod(&two, n).unwrap() + &c + n) % n;
+        y = (y.pow_mod(&two, n).unwrap() + &c + n) % n;
+
+        d = (&x-&y).complete().abs().gcd(n);
+
+        if &d == n {
+            trace!("retrying pollard rho for {}", n);
+            return pollard_rho(n)
+        }
+    }
+    d
+}
+
+// Items stored as factorised primes
+type Item = Vec<Integer>;
+
+pub struct SmartMonke {
+    items: VecDeque<Item>,
+    operation: Box<dyn Fn(&mut Item)>,
+    throws_to: Box<dyn Fn(&Item) -> usize>,
+    items_handled_count: u64
+}
+
+impl SmartMonke {
+    fn new(notes: &MonkeNotes) -> Self {
+        let items = notes.items
+                        .iter()
+                        .map(|&num| factorise_to_primes(&Integer::from(num)))
+                        .collect();
+
+        let operation: Box<dyn Fn(&mut Item)> = match (notes.op, notes.rhs) {
+            (Op::Mul, Operand::Num(rhs)) => Box::new(move |item| item.push(Integer::from(rhs))),
+            (Op::Mul, Operand::Old) => Box::new(move |item| item.extend(item.clone())),
+            (Op::Add, Operand::Num(rhs)) => Box::new(move |item| {
+                let num = Integer::product(item.iter()).complete();
+                trace!("refactorising {} + {}", num, rhs);
+                *item = factorise_to_primes(
+                    &(num + rhs)
+                    )
+            }),
+            (Op::Add, Operand::Old) => Box::new(move |item| item.push(Integer::from(2))),
+        };
+        
+        let throws_to: Box<dyn Fn(&Item) -> usize> = {
+            let division = Integer::from(notes.divisor);
+            let (true_monke, false_monke) = (notes.true_monke, notes.false_monke);
+    
+            Box::new(move |item| if item.contains(&division) { true_monke } else { false_monke })
+        };
+
+        Self {
+            items,
+            operation,
+            throws_to,
+            items_handled_count: 0
+        }
+
+    }
+}
+
+impl fmt::Debug for SmartMonke {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Monke {{ items: {:?}, items_handled_count: {}}}", self.items, self.items_handled_count)
+    }
+}
+
+pub fn run_big_simulation(notes: &[MonkeNotes], iterations: u64, worry_div: u64) -> Integer {
+    let mut all_monke: Vec<Monke> = notes
+        .iter()
+        .map(|notes| Monke::new(notes, worry_div))
+        .collect();
+
+    for j in 0..iterations {
+        trace!("round {}", j);
+        for i in 0..all_monke.len() {
+            while let Some(item) = all_monke[i].items.pop_front() {
+                let new_item_worry = (all_monke[i].operation)(&item);
+                let throws_to = (all_monke[i].throws_to)(&new_item_worry);
+                all_monke[throws_to].items.push_back(new_item_worry);
+                all_monke[i].items_handled_count += 1;
+            }
+        }
+    }
+
+    let mut activity: Vec<u64> = all_monke.iter().map(|monke| monke.items_handled_count).collect();
+    activity.sort();
+    activity.iter().rev().take(2).product::<Integer>()
+}
+
+pub fn run_simulation2(notes: &[MonkeNotes], iterations: u64) -> Integer {
+    let mut all_monke: Vec<SmartMonke> = notes
+        .iter()
+        .map(SmartMonke::new)
+        .collect();
+
+    for j in 0..iterations {
+        trace!("round {}", j);
+        for i in 0..all_monke.len() {
+            while let Some(mut item) = all_monke[i].items.pop_front() {
+                (all_monke[i].operation)(&mut item);
+                let throws_to = (all_monke[i].throws_to)(&item);
+                all_monke[throws_to].items.push_back(item);
+                all_monke[i].items_handled_count += 1;
+            }
+        }
+    }
+
+    debug!("{:?}", all_monke);
+    let mut activity: Vec<u64> = all_monke.iter().map(|monke| monke.items_handled_count).collect();
+    activity.sort();
+    activity.iter().rev().take(2).product::<Integer>()
+}
@@ -0,0 +1,265 @@
+use std::collections::VecDeque;
+use lazy_static::lazy_static;
+use regex::Regex;
+use common::{parse_at, ParseError};
+use tracing::trace;
+
+#[cfg(feature = "bigint")]
+mod bigint;
+#[cfg(feature = "bigint")]
+pub use bigint::{run_big_simulation, run_simulation2, Monke, SmartMonke};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Op {
+    Add,
+    Mul,
+}
+
+/// Right hand side of a monkey's operation
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Operand {
+    Old,
+    Num(u64),
+}
+
+/// A single monkey's notes, which each flavour of monkey is built from
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MonkeNotes {
+    items: Vec<u64>,
+    op: Op,
+    rhs: Operand,
+    divisor: u64,
+    true_monke: usize,
+    false_monke: usize,
+}
+
+/// Error for a monkey's notes: points at the line that should contain `key`, or the start of
+/// the notes if there is no such line
+fn missing(input: &str, info: &str, key: &str, expected: &str) -> ParseError {
+    let line = info
+        .lines()
+        .find(|line| line.contains(key))
+        .or_else(|| info.lines().next())
+        .unwrap_or(info);
+    ParseError::at(input, line, expected)
+}
+
+pub fn parse_notes(input: &str) -> Result<Vec<MonkeNotes>, ParseError> {
+    lazy_static! {
+        static ref START_RE: Regex = Regex::new(
+            r"Starting items: ([0-9]*(,\s[0-9]*)*)\n")
+            .unwrap();
+        static ref OP_RE: Regex = Regex::new(
+            r"Operation: new = old ([\*\+]) ([a-z0-9]*)\n")
+            .unwrap();
+        static ref TEST_RE: Regex = Regex::new(
+            r"Test: divisible by ([0-9]*)\n\s*[a-zA-Z\s:]*([0-9]*)\n\s*[a-zA-Z\s:]*([0-9]*)")
+            .unwrap();
+    }
+    let monke_raw = input.split("\n\n").collect::<Vec<&str>>();
+    let monke_count = monke_raw.len();
+
+    let notes = monke_raw.iter().map(|info| {
+        let items = START_RE.captures(info)
+                        .ok_or_else(|| missing(input, info, "Starting items", "`Starting items: ` then worry levels"))?
+                        .get(1)
+                        .unwrap()
+                        .as_str()
+                        .split(", ")
+                        .map(|num| parse_at::<u64>(input, num, "a worry level"))
+                        .collect::<Result<Vec<u64>, _>>()?;
+
+        let cap = OP_RE.captures(info)
+            .ok_or_else(|| missing(input, info, "Operation", "`Operation: new = old ` then `*` or `+` and an operand"))?;
+        let op = match cap.get(1).unwrap().as_str() {
+            "*" => Op::Mul,
+            _ => Op::Add,
+        };
+        let rhs = match cap.get(2).unwrap().as_str() {
+            "old" => Operand::Old,
+            rhs_str => Operand::Num(parse_at(input, rhs_str, "`old` or a number")?),
+        };
+
+        let cap = TEST_RE.captures(info)
+            .ok_or_else(|| missing(input, info, "Test", "`Test: divisible by ` then the throw targets"))?;
+        let divisor_str = cap.get(1).unwrap().as_str();
+        let divisor = match parse_at::<u64>(input, divisor_str, "a divisor")? {
+            0 => return Err(ParseError::at(input, divisor_str, "a non-zero divisor")),
+            divisor => divisor,
+        };
+        let target = |i: usize| {
+            let target_str = cap.get(i).unwrap().as_str();
+            let expected = format!("a monkey from 0 to {}", monke_count - 1);
+            match parse_at::<usize>(input, target_str, &expected)? {
+                target if target < monke_count => Ok(target),
+                _ => Err(ParseError::at(input, target_str, expected)),
+            }
+        };
+
+        Ok(MonkeNotes {
+            items,
+            op,
+            rhs,
+            divisor,
+            true_monke: target(2)?,
+            false_monke: target(3)?,
+        })
+    }).collect::<Result<Vec<MonkeNotes>, ParseError>>()?;
+
+    for i in 0..notes.len() {
+        if divisors_lcm(notes[..=i].iter().map(|notes| notes.divisor)).is_none() {
+            let divisor_str = TEST_RE.captures(monke_raw[i]).unwrap().get(1).unwrap().as_str();
+            return Err(ParseError::at(input, divisor_str, "a divisor keeping the monkeys' common multiple within 64 bits"));
+        }
+    }
+    Ok(notes)
+}
+
+/// Writes notes back out in the puzzle's layout, numbering the monkeys in order
+pub fn render_notes(notes: &[MonkeNotes]) -> String {
+    notes.iter().enumerate().map(|(i, notes)| {
+        let items = notes.items.iter().map(|item| item.to_string()).collect::<Vec<_>>();
+        let op = match notes.op {
+            Op::Add => '+',
+            Op::Mul => '*',
+        };
+        let rhs = match notes.rhs {
+            Operand::Old => "old".to_string(),
+            Operand::Num(n) => n.to_string(),
+        };
+        format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            i, items.join(", "), op, rhs, notes.divisor, notes.true_monke, notes.false_monke
+        )
+    }).collect::<Vec<_>>().join("\n")
+}
+
+struct ModMonke {
+    items: VecDeque<u128>,
+    /// `None` once the worry level no longer fits in 128 bits
+    operation: Box<dyn Fn(u128) -> Option<u128>>,
+    throws_to: Box<dyn Fn(u128) -> usize>,
+    divisor: u64,
+    items_handled_count: u64
+}
+
+impl ModMonke {
+    fn new(notes: &MonkeNotes) -> Self {
+        let items = notes.items.iter().map(|&item| item as u128).collect();
+
+        let operation: Box<dyn Fn(u128) -> Option<u128>> = match (notes.op, notes.rhs) {
+            (Op::Mul, Operand::Num(rhs)) => Box::new(move |item| item.checked_mul(rhs as u128)),
+            (Op::Mul, Operand::Old) => Box::new(move |item| item.checked_mul(item)),
+            (Op::Add, Operand::Num(rhs)) => Box::new(move |item| item.checked_add(rhs as u128)),
+            (Op::Add, Operand::Old) => Box::new(move |item| item.checked_add(item)),
+        };
+        
+        let divisor = notes.divisor;
+        let throws_to: Box<dyn Fn(u128) -> usize> = {
+            let (true_monke, false_monke) = (notes.true_monke, notes.false_monke);
+            trace!("if div by {} throw to {} else {}", divisor, true_monke, false_monke);
+            Box::new(move |item| if (item % divisor as u128) == 0 { true_monke } else { false_monke })
+        };
+
+        Self {
+            items,
+            operation,
+            throws_to,
+            divisor,
+            items_handled_count: 0
+        }
+
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Least common multiple of the divisors, `None` if it doesn't fit in 64 bits
+fn divisors_lcm(divisors: impl IntoIterator<Item = u64>) -> Option<u64> {
+    divisors.into_iter().try_fold(1u64, |lcm, divisor| (lcm / gcd(lcm, divisor)).checked_mul(divisor))
+}
+
+/// Monkey business after `iterations` rounds, dividing worry by `worry_div` after each
+/// inspection
+///
+/// Fails with the index of the monkey whose operation took a worry level past 128 bits, which
+/// can only happen when `worry_div` isn't 1.
+pub fn run_simulation(notes: &[MonkeNotes], iterations: u64, worry_div: u64) -> Result<u64, usize> {
+    let mut all_monke: Vec<ModMonke> = notes
+        .iter()
+        .map(ModMonke::new)
+        .collect();
+
+    // Every test only cares about divisibility, so worry can be kept modulo a common multiple
+    // of the divisors. Below a 64-bit modulus neither operand reaches 64 bits, so nothing
+    // overflows. That stops holding once worry is also divided down after each inspection:
+    // x / 3 modulo m depends on x modulo 3m, and the next division on x modulo 9m, so then
+    // worry is kept exact.
+    let modulus = match worry_div {
+        1 => Some(divisors_lcm(all_monke.iter().map(|monke| monke.divisor))
+            .expect("parse_notes checks the divisors' common multiple fits in 64 bits") as u128),
+        _ => None,
+    };
+
+    for j in 0..iterations {
+        trace!("round {}", j);
+        for i in 0..all_monke.len() {
+            while let Some(item) = all_monke[i].items.pop_front() {
+                let new_item_worry = (all_monke[i].operation)(item).ok_or(i)? / worry_div as u128;
+                let new_item_worry = modulus.map_or(new_item_worry, |m| new_item_worry % m);
+                let throws_to = (all_monke[i].throws_to)(new_item_worry);
+                all_monke[throws_to].items.push_back(new_item_worry);
+                all_monke[i].items_handled_count += 1;
+            }
+        }
+    }
+
+    let mut activity: Vec<u64> = all_monke.iter().map(|monke| monke.items_handled_count).collect();
+    activity.sort();
+    Ok(activity.iter().rev().take(2).product())
+}
+
+
+/// Error for `run_simulation` failing on monkey `monke`, pointing at its operation
+fn overflow(input: &str, monke: usize) -> ParseError {
+    let info = input.split("\n\n").nth(monke).unwrap();
+    missing(input, info, "Operation", "an operation keeping worry levels within 128 bits")
+}
+
+pub fn solve_part1(input: &str) -> Result<u64, ParseError> {
+    run_simulation(&parse_notes(input)?, 20, 3).map_err(|monke| overflow(input, monke))
+}
+
+pub fn solve_part2(input: &str) -> Result<u64, ParseError> {
+    run_simulation(&parse_notes(input)?, 10000, 1).map_err(|monke| overflow(input, monke))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BIG_WORRY: &str = include_str!("../test_input_big_worry");
+
+    #[test]
+    fn worry_past_128_bits_is_an_error() {
+        let e = solve_part1(BIG_WORRY).unwrap_err();
+        assert_eq!((e.line, e.found.as_str()), (17, "  Operation: new = old * 19"));
+        assert_eq!(e.expected, "an operation keeping worry levels within 128 bits");
+        assert!(solve_part2(BIG_WORRY).is_ok());
+    }
+
+    #[test]
+    fn rejects_divisors_whose_common_multiple_overflows() {
+        let notes = BIG_WORRY
+            .replacen("divisible by 13", "divisible by 4294967291", 1)
+            .replacen("divisible by 17", "divisible by 4294967279", 1);
+        assert!(parse_notes(&notes.replacen("divisible by 19", "divisible by 1", 1)).is_ok());
+        let e = parse_notes(&notes.replacen("divisible by 19", "divisible by 3", 1)).unwrap_err();
+        assert_eq!((e.line, e.found.as_str()), (18, "3"));
+        assert_eq!(e.expected, "a divisor keeping the monkeys' common multiple within 64 bits");
+        // Repeated factors don't count twice
+        assert!(parse_notes(&notes.replacen("divisible by 19", "divisible by 4294967291", 1)).is_ok());
+    }
+}
@@ -0,0 +1,137 @@
+//! `run_simulation` keeps worry levels exact in `u128` when they are divided by 3 after each
+//! inspection, and `ModMonke` reduces them modulo a common multiple of the divisors when they
+//! aren't. Both are checked against a plain `u128` simulation here, written from the notes'
+//! text. With `cargo test -p day11 --features bigint` they are also checked against `Monke`,
+//! which keeps exact worry levels as big integers, and `SmartMonke`, which keeps them
+//! factorised. Exact worry levels grow quickly without the division by 3, so the rounds are
+//! kept short there.
+
+use proptest::prelude::*;
+
+fn input(seed: u64, monkeys: usize) -> String {
+    inputgen::generate(11, seed, monkeys).unwrap()
+}
+
+/// One monkey's notes, read straight from the puzzle's layout
+struct Notes {
+    items: Vec<u128>,
+    op: char,
+    /// `None` for `old`
+    rhs: Option<u128>,
+    divisor: u128,
+    targets: (usize, usize),
+}
+
+fn last_word(line: &str) -> &str {
+    line.rsplit(' ').next().unwrap()
+}
+
+fn read_notes(input: &str) -> Vec<Notes> {
+    input
+        .split("\n\n")
+        .map(|block| {
+            let lines = block.lines().collect::<Vec<&str>>();
+            let items = lines[1].split(": ").nth(1).unwrap().split(", ").map(|item| item.parse().unwrap()).collect();
+            let operation = lines[2].split("old ").nth(1).unwrap();
+            Notes {
+                items,
+                op: operation.chars().next().unwrap(),
+                rhs: last_word(operation).parse().ok(),
+                divisor: last_word(lines[3]).parse().unwrap(),
+                targets: (last_word(lines[4]).parse().unwrap(), last_word(lines[5]).parse().unwrap()),
+            }
+        })
+        .collect()
+}
+
+/// Monkey business with exact `u128` worry levels, `None` once they no longer fit
+fn exact_business(input: &str, rounds: u64, worry_div: u128) -> Option<u64> {
+    let notes = read_notes(input);
+    let mut items = notes.iter().map(|notes| notes.items.clone()).collect::<Vec<_>>();
+    let mut handled = vec![0u64; notes.len()];
+    for _ in 0..rounds {
+        for (i, notes) in notes.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                let rhs = notes.rhs.unwrap_or(item);
+                let worry = match notes.op {
+                    '*' => item.checked_mul(rhs)?,
+                    _ => item.checked_add(rhs)?,
+                } / worry_div;
+                let target = if worry.is_multiple_of(notes.divisor) { notes.targets.0 } else { notes.targets.1 };
+                items[target].push(worry);
+                handled[i] += 1;
+            }
+        }
+    }
+    handled.sort();
+    Some(handled.iter().rev().take(2).product())
+}
+
+#[test]
+fn big_worry_levels_match_u128_reference() {
+    let input = include_str!("../test_input_big_worry");
+    let notes = day11::parse_notes(input).unwrap();
+    assert!(exact_business(input, 2, 3).is_some());
+    for rounds in 1..=20 {
+        assert_eq!(day11::run_simulation(&notes, rounds, 3).ok(), exact_business(input, rounds, 3));
+        if let Some(business) = exact_business(input, rounds, 1) {
+            assert_eq!(day11::run_simulation(&notes, rounds, 1), Ok(business));
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn checked_worry_with_relief_matches_u128_reference(seed in any::<u64>(), monkeys in 2usize..9) {
+        let input = input(seed, monkeys);
+        let business = exact_business(&input, 20, 3).expect("generated worry levels fit in 128 bits");
+        prop_assert_eq!(day11::run_simulation(&day11::parse_notes(&input).unwrap(), 20, 3), Ok(business));
+    }
+
+    #[test]
+    fn mod_monke_without_relief_matches_u128_reference(seed in any::<u64>(), monkeys in 2usize..9, rounds in 1u64..8) {
+        let input = input(seed, monkeys);
+        let business = exact_business(&input, rounds, 1).expect("generated worry levels fit in 128 bits");
+        prop_assert_eq!(day11::run_simulation(&day11::parse_notes(&input).unwrap(), rounds, 1), Ok(business));
+    }
+}
+
+#[cfg(feature = "bigint")]
+mod bigint {
+    use super::*;
+    use rug::Integer;
+
+    fn notes(seed: u64, monkeys: usize) -> Vec<day11::MonkeNotes> {
+        day11::parse_notes(&input(seed, monkeys)).unwrap()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn checked_worry_with_relief_matches_big_worry(seed in any::<u64>(), monkeys in 2usize..9) {
+            let notes = notes(seed, monkeys);
+            prop_assert_eq!(
+                Integer::from(day11::run_simulation(&notes, 20, 3).unwrap()),
+                day11::run_big_simulation(&notes, 20, 3)
+            );
+        }
+
+        #[test]
+        fn mod_monke_without_relief_matches_big_worry(seed in any::<u64>(), monkeys in 2usize..9, rounds in 1u64..4) {
+            let notes = notes(seed, monkeys);
+            prop_assert_eq!(
+                Integer::from(day11::run_simulation(&notes, rounds, 1).unwrap()),
+                day11::run_big_simulation(&notes, rounds, 1)
+            );
+        }
+
+        #[test]
+        fn smart_monke_matches_big_worry(seed in any::<u64>(), monkeys in 2usize..9, rounds in 1u64..4) {
+            let notes = notes(seed, monkeys);
+            prop_assert_eq!(day11::run_simulation2(&notes, rounds), day11::run_big_simulation(&notes, rounds, 1));
+        }
+    }
+}
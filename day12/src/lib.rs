@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+use common::ParseError;
+use grid::{Coord, Grid};
+use tracing::trace;
+
+struct Heightmap {
+    grid: Grid<i32>,
+    start: Coord,
+    end: Coord
+}
+
+impl Heightmap {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let chars = Grid::parse(input, "a height a-z, S or E", |c| {
+            Some(c).filter(|c| matches!(c, 'a'..='z' | 'S' | 'E'))
+        })?;
+
+        let start = chars.position(|&c| c == 'S')
+            .ok_or_else(|| ParseError::after(input, input, "a start position `S`"))?;
+        let end = chars.position(|&c| c == 'E')
+            .ok_or_else(|| ParseError::after(input, input, "a best signal position `E`"))?;
+
+        let grid = chars.map(|&c| match c {
+            'S' => 0,
+            'E' => 'z' as i32 - 'a' as i32,
+            c => c as i32 - 'a' as i32,
+        });
+
+        Ok(Self {
+            grid,
+            start,
+            end
+        })
+    }
+
+    fn neighours_at(&self, i: usize) -> Vec<usize> {
+        self.grid.neighbours4(self.grid.coord_at(i))
+            .map(|coord| self.grid.index_of(coord))
+            .collect()
+    }
+
+    fn dist_between(&self, from: usize, to: usize) -> u32 {
+        if self.grid[to] <= self.grid[from] + 1 {
+            1 // assuming neighbours
+        }
+        else {
+            u32::MAX
+        }
+    }
+}
+
+/// Squares on the fewest-steps path from E back to S, excluding E
+fn dijkstra(graph: &Heightmap) -> Vec<usize> {
+    let mut dist = Grid::new_default(u32::MAX, graph.grid.width(), graph.grid.height());
+    let mut prev: Grid<Option<usize>> = Grid::new_default(None, graph.grid.width(), graph.grid.height());
+    let mut unvisited: HashSet<usize> = (0..graph.grid.len()).collect();
+
+    dist[graph.start] = 0;
+
+    while !unvisited.is_empty() {
+        let min_dist = unvisited.iter().map(|i| (i, dist[*i])).min_by_key(|(_, d)| *d).unwrap();
+        let min_dist_idx = *min_dist.0;
+        let min_dist_val = min_dist.1;
+        unvisited.remove(&min_dist_idx);
+
+        if graph.grid.coord_at(min_dist_idx) == graph.end {
+            break
+        }
+
+        let neighbours: Vec<usize> = graph.neighours_at(min_dist_idx)
+                                          .into_iter()
+                                          .filter(|v| unvisited.contains(v)).collect();
+        
+
+        for neighbour in neighbours {
+            let alt = min_dist_val.saturating_add(graph.dist_between(min_dist_idx, neighbour));
+            if alt < dist[neighbour] {
+                dist[neighbour] = alt;
+                prev[neighbour] = Some(min_dist_idx);
+            }
+        }
+    }
+
+    let mut sequence: Vec<usize> = Vec::new();
+    let mut u = graph.grid.index_of(graph.end);
+    while let Some(next) = prev[u] {
+        sequence.push(next);
+        u = next
+    }
+
+    sequence
+}
+
+/// Fewest steps from any `a` to E, `None` if no `a` can reach it
+fn reverse_dijkstra(graph: &Heightmap) -> Option<usize> {
+    let mut dist = Grid::new_default(u32::MAX, graph.grid.width(), graph.grid.height());
+    let mut prev: Grid<Option<usize>> = Grid::new_default(None, graph.grid.width(), graph.grid.height());
+    let mut unvisited: HashSet<usize> = (0..graph.grid.len()).collect();
+
+    dist[graph.end] = 0;
+
+    let mut end = None;
+
+    while !unvisited.is_empty() {
+        let min_dist = unvisited.iter().map(|i| (i, dist[*i])).min_by_key(|(_, d)| *d).unwrap();
+        let min_dist_idx = *min_dist.0;
+        let min_dist_val = min_dist.1;
+        unvisited.remove(&min_dist_idx);
+
+        if min_dist_val == u32::MAX {
+            break; // everything left is out of reach
+        }
+        if graph.grid[min_dist_idx] == 0 {
+            end = Some(min_dist_idx);
+            break;
+        }
+
+        let neighbours: Vec<usize> = graph.neighours_at(min_dist_idx)
+                                          .into_iter()
+                                          .filter(|v| unvisited.contains(v)).collect();
+        
+
+        for neighbour in neighbours {
+            let alt = min_dist_val.saturating_add(graph.dist_between(neighbour, min_dist_idx));
+            if alt < dist[neighbour] {
+                dist[neighbour] = alt;
+                prev[neighbour] = Some(min_dist_idx);
+            }
+        }
+    }
+
+    let mut sequence: Vec<usize> = Vec::new();
+    let mut u = end?;
+    while let Some(next) = prev[u] {
+        sequence.push(next);
+        u = next
+    }
+
+    Some(sequence.len())
+}
+
+/// Error for E being out of reach, pointing at it
+fn unreachable(input: &str, expected: &str) -> ParseError {
+    let end = input.find('E').expect("Heightmap::new checks there is an E");
+    ParseError::at(input, &input[end..end + 1], expected)
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let map = Heightmap::new(input)?;
+
+    trace!("heightmap from {:?} to {:?}:\n{:?}", map.start, map.end, map.grid);
+    match dijkstra(&map).len() {
+        0 => Err(unreachable(input, "an `E` that can be reached from `S`")),
+        steps => Ok(steps),
+    }
+}
+
+/// Heights from 0 at `a` to 25 at `z`, and the squares on a fewest-steps path from S to E, which
+/// is empty if E can't be reached
+pub fn shortest_path(input: &str) -> Result<(Grid<i32>, Vec<Coord>), ParseError> {
+    let map = Heightmap::new(input)?;
+    let mut path = dijkstra(&map);
+    if path.is_empty() {
+        return Ok((map.grid, Vec::new()));
+    }
+    path.reverse();
+    path.push(map.grid.index_of(map.end));
+    let path = path.into_iter().map(|i| map.grid.coord_at(i)).collect();
+    Ok((map.grid, path))
+}
+
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    let map = Heightmap::new(input)?;
+    reverse_dijkstra(&map).ok_or_else(|| unreachable(input, "an `E` that can be reached from an `a`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreachable_end_is_an_error() {
+        let e = solve_part1("SE\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, 2, "E"));
+        assert_eq!(e.expected, "an `E` that can be reached from `S`");
+        assert_eq!(solve_part2("SzE\naaa\n").unwrap_err().expected, "an `E` that can be reached from an `a`");
+        let climb = "SbcdefghijklmnopqrstuvwxyE\n";
+        assert_eq!((solve_part1(climb), solve_part2(climb)), (Ok(25), Ok(25)));
+    }
+}
@@ -0,0 +1,31 @@
+//! Part 2 searches backwards from E for the nearest `a`, so it should find the same distance as
+//! searching forwards from every `a` in turn and keeping the shortest.
+
+use proptest::prelude::*;
+
+/// The same heightmap with S moved to byte `start`, which must be an `a`
+fn start_at(input: &str, start: usize) -> String {
+    let mut bytes = input.replace('S', "a").into_bytes();
+    bytes[start] = b'S';
+    String::from_utf8(bytes).unwrap()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn reverse_search_finds_the_closest_start(seed in any::<u64>(), side in 2usize..6) {
+        let input = inputgen::generate(12, seed, side).unwrap();
+
+        // A forward search that can't reach E is an error
+        let shortest = input
+            .match_indices(['a', 'S'])
+            .filter_map(|(i, _)| day12::solve_part1(&start_at(&input, i)).ok())
+            .min();
+
+        prop_assert_eq!(day12::solve_part2(&input).ok(), shortest);
+        if let Ok(steps) = day12::solve_part1(&input) {
+            prop_assert!(day12::solve_part2(&input).unwrap() <= steps);
+        }
+    }
+}
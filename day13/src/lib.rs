@@ -0,0 +1,254 @@
+use std::fmt;
+use common::{parse_at, ParseError};
+use tracing::{debug, trace};
+
+/// A packet, or one of the values nested in it
+#[derive(Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(u32),
+    List(Vec<Value>)
+}
+
+type Pair = (Value, Value);
+
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(i) => write!(f, "{}", i),
+            Value::List(v) => write!(f, "{:?}", v)
+        }
+    }
+}
+
+/// Writes the packet back out the way the puzzle prints it, e.g. `[1,[2,3]]`
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(i) => write!(f, "{}", i),
+            Value::List(v) => {
+                write!(f, "[")?;
+                for (i, value) in v.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let packet_pairs: Vec<Pair> = input
+        .split("\n\n")
+        .map(|pair_str| {
+            let lines: Vec<&str> = pair_str.lines().collect(); 
+            let left = lines.first().copied().unwrap_or(pair_str);
+            let left_packet = parse_packet(input, left)?;
+            let right = lines
+                .get(1)
+                .ok_or_else(|| ParseError::after(input, left, "a second packet on the next line"))?;
+            let right_packet = parse_packet(input, right)?;
+            if let Some(extra) = lines.get(2) {
+                return Err(ParseError::at(input, extra, "a blank line between pairs"));
+            }
+
+            Ok((left_packet, right_packet))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let in_order_pairs = packet_pairs.iter().enumerate().filter_map(|(idx, pair)| {                                                                    
+        if is_in_order(pair) {
+            debug!("pair {} in order", idx + 1);
+            Some(idx + 1)
+        }
+        else {
+            debug!("pair {} out of order", idx + 1);
+            None
+        }
+    }).collect::<Vec<usize>>();
+    
+    debug!("in order pairs: {:?}", in_order_pairs);
+    Ok(in_order_pairs.iter().sum::<usize>())
+}
+
+fn parse_block(packet_vec: &[String]) -> Value {
+    let mut in_sublist: Option<usize> = None;
+    let mut open_brace = 0;
+    let list = packet_vec.iter().enumerate().filter_map(|(i, el)| {
+        match el.as_str() {
+            "[" => {
+                if in_sublist.is_none() {
+                    in_sublist = Some(i + 1);
+                }
+                open_brace += 1;
+                None
+            },
+            "]" => {
+                if open_brace == 1 {
+                    if let Some(start) = in_sublist {
+                        in_sublist = None;
+                        open_brace -= 1;
+                        return Some(parse_block(&packet_vec[start..i]))
+                    }
+                }
+                open_brace -= 1;
+                None // end of block
+            },
+            _ => if in_sublist.is_some() {
+                None
+            }
+            else {
+                Some(Value::Int(el.parse::<u32>().expect("numbers are checked by tokenise")))
+            }
+        }
+
+    })
+    .collect::<Vec<Value>>();
+
+    Value::List(list)
+}
+
+/// Deepest nesting `tokenise` accepts, as parsing and comparing packets recurse once per list
+const MAX_DEPTH: usize = 100;
+
+/// What may follow `prev` in a packet, with `depth` lists still open
+fn expected_after(prev: Option<char>, depth: usize) -> &'static str {
+    match prev {
+        None => "`[`",
+        Some(']') if depth == 0 => "end of line",
+        Some('[') => "an integer, `[` or `]`",
+        Some(',') => "an integer or `[`",
+        Some(']') => "`,` or `]`",
+        Some(_) => "a digit, `,` or `]`",
+    }
+}
+
+/// Splits a packet into `[`, `]` and integer tokens, checking it is a single well formed list
+fn tokenise(input: &str, packet_str: &str) -> Result<Vec<String>, ParseError> {
+    let mut packet_vec: Vec<String> = Vec::new();
+    let mut depth = 0;
+    let mut prev: Option<char> = None;
+    let mut num_start: Option<usize> = None;
+
+    for (i, c) in packet_str.char_indices() {
+        let allowed = match (prev, c) {
+            (Some(']'), _) if depth == 0 => false,
+            (None | Some('[' | ','), '[') => true,
+            (Some('[' | ',' | '0'..='9'), '0'..='9') => true,
+            (Some('[' | ']' | '0'..='9'), ']') => true,
+            (Some(']' | '0'..='9'), ',') => true,
+            _ => false,
+        };
+        if !allowed {
+            return Err(ParseError::at(input, &packet_str[i..i + c.len_utf8()], expected_after(prev, depth)));
+        }
+
+        if c.is_ascii_digit() {
+            num_start.get_or_insert(i);
+        } else if let Some(start) = num_start.take() {
+            let num = &packet_str[start..i];
+            parse_at::<u32>(input, num, "an integer that fits in 32 bits")?;
+            packet_vec.push(num.to_string());
+        }
+
+        match c {
+            '[' => {
+                depth += 1;
+                if depth > MAX_DEPTH {
+                    return Err(ParseError::at(input, &packet_str[i..i + 1], format!("at most {} nested lists", MAX_DEPTH)));
+                }
+                packet_vec.push(c.to_string());
+            },
+            ']' => {
+                depth -= 1;
+                packet_vec.push(c.to_string());
+            },
+            _ => (),
+        }
+        prev = Some(c);
+    }
+
+    if prev.is_none() || depth > 0 {
+        return Err(ParseError::after(input, packet_str, expected_after(prev, depth)));
+    }
+    Ok(packet_vec)
+}
+
+/// Parses one packet line, `packet_str`, which must be a slice of `input`
+pub fn parse_packet(input: &str, packet_str: &str) -> Result<Value, ParseError> {
+    let packet_vec = tokenise(input, packet_str)?;
+
+    trace!("parsed packet {}", packet_str);
+    Ok(parse_block(&packet_vec[1..]))
+}
+
+fn is_in_order(pair: &Pair) -> bool {
+    trace!("Evaluate {:?} vs {:?}", pair.0, pair.1);
+    let left = match &pair.0 {
+        Value::List(l) => l.clone(),
+        Value::Int(i) => vec![Value::Int(*i)]
+    };
+
+    let right = match &pair.1 {
+        Value::List(l) => l.clone(),
+        Value::Int(i) => vec![Value::Int(*i)]
+    };
+
+    for i in 0..std::cmp::max(left.len(), right.len()) {
+        let left_el = left.get(i);
+        let right_el = right.get(i);
+        
+        match (left_el, right_el) {
+            (Some(left_val), Some(right_val)) => {
+                match (left_val, right_val) {
+                    (Value::Int(left_int), Value::Int(right_int)) => {
+                        if left_int > right_int { 
+                            trace!("left {} > right {}", left_int, right_int);
+                            return false
+                        }
+                        else if left_int < right_int {
+                            trace!("left {} < right {}", left_int, right_int);
+                            return true
+                        }
+                        else {
+                            trace!("left {} == right: {}", left_int, right_int);
+                        }
+                    },
+                    (left_sub, right_sub) => if !is_in_order(&(left_sub.clone(), right_sub.clone())) { 
+                        trace!("left: {:?}, right: {:?} not in order", left_sub, right_sub);
+                        return false 
+                    }
+                }
+            },
+            (None, Some(_)) => {
+                trace!("left ran out of values first!");
+                return true
+            },// left ran out of values first
+            (Some(_), None) => {
+                trace!("right ran out of values first!");
+                return false
+            }, // right ran out of values first
+            (None, None) => return true
+        }
+    }
+    trace!("Got to the end, all in order");
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_packets_nested_too_deep() {
+        let deepest = format!("{}{}", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+        assert_eq!(parse_packet(&deepest, &deepest).unwrap().to_string(), deepest);
+
+        let input = format!("{}\n[]\n", "[".repeat(10_000) + &"]".repeat(10_000));
+        let e = solve_part1(&input).unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, MAX_DEPTH + 1, "["));
+        assert_eq!(e.expected, "at most 100 nested lists");
+    }
+}
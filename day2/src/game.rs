@@ -0,0 +1,223 @@
+//! Game definitions: the moves, their shape scores and which move beats which
+//!
+//! A definition has a `name score` line per move, then `winner beats loser...` lines covering
+//! every pairing of two different moves. Blank lines and lines starting with `#` are ignored.
+
+use common::{parse_at, ParseError};
+
+/// A move, as its position in the game's list of moves
+pub type Move = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Loss => "lose",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    scores: Vec<u32>,
+    /// `beats[a][b]` if `a` beats `b`
+    beats: Vec<Vec<bool>>,
+}
+
+/// Longest game whose move codes don't run into each other, A upwards and Z downwards
+const MAX_MOVES: usize = 13;
+
+const ROCK_PAPER_SCISSORS: &str = include_str!("../games/rps.txt");
+
+impl Game {
+    /// Reads a game definition, checking that every pairing of two moves has a winner
+    pub fn parse(config: &str) -> Result<Game, ParseError> {
+        let mut tokens: Vec<&str> = vec![];
+        let mut game = Game { names: vec![], scores: vec![], beats: vec![] };
+        let lines = config.lines().filter(|line| !line.trim().is_empty() && !line.starts_with('#'));
+
+        for line in lines {
+            let mut words = line.split_whitespace();
+            let name = words.next().unwrap();
+            let second = words.next().ok_or_else(|| ParseError::after(config, line, "a shape score or `beats`"))?;
+            if second != "beats" {
+                if tokens.contains(&name) {
+                    return Err(ParseError::at(config, name, "a move not already listed"));
+                }
+                if tokens.len() == MAX_MOVES {
+                    return Err(ParseError::at(config, name, format!("at most {} moves", MAX_MOVES)));
+                }
+                tokens.push(name);
+                for row in &mut game.beats {
+                    row.push(false);
+                }
+                game.beats.push(vec![false; tokens.len()]);
+                game.names.push(name.to_string());
+                game.scores.push(parse_at(config, second, "a shape score")?);
+                continue;
+            }
+
+            let find = |token: &str| {
+                tokens.iter().position(|&t| t == token).ok_or_else(|| ParseError::at(config, token, "a move listed earlier"))
+            };
+            let winner = find(name)?;
+            let losers = words.collect::<Vec<&str>>();
+            if losers.is_empty() {
+                return Err(ParseError::after(config, line, "the moves it beats"));
+            }
+            for loser_token in losers {
+                let loser = find(loser_token)?;
+                if loser == winner || game.beats[loser][winner] {
+                    return Err(ParseError::at(config, loser_token, format!("a move that {} can beat", name)));
+                }
+                game.beats[winner][loser] = true;
+            }
+        }
+
+        if tokens.len() < 2 {
+            return Err(ParseError::after(config, config, "at least two moves"));
+        }
+        for a in game.moves() {
+            for b in game.moves().filter(|&b| b > a) {
+                if !game.beats[a][b] && !game.beats[b][a] {
+                    let expected = format!("`{} beats {}` or `{} beats {}`", tokens[a], tokens[b], tokens[b], tokens[a]);
+                    return Err(ParseError::at(config, tokens[b], expected));
+                }
+            }
+            if !game.beats[a].contains(&true) || game.moves().all(|b| !game.beats[b][a]) {
+                return Err(ParseError::at(config, tokens[a], "a move that both wins and loses against something"));
+            }
+        }
+        Ok(game)
+    }
+
+    /// The puzzle's game, from `games/rps.txt`
+    pub fn rock_paper_scissors() -> Game {
+        Game::parse(ROCK_PAPER_SCISSORS).unwrap()
+    }
+
+    pub fn moves(&self) -> std::ops::Range<Move> {
+        0..self.names.len()
+    }
+
+    pub fn name(&self, play: Move) -> &str {
+        &self.names[play]
+    }
+
+    pub fn score(&self, play: Move) -> u32 {
+        self.scores[play]
+    }
+
+    pub fn outcome(&self, opponent: Move, player: Move) -> Outcome {
+        if self.beats[player][opponent] {
+            Outcome::Win
+        } else if self.beats[opponent][player] {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// Moves with `outcome` against `opponent`; every move both wins and loses against
+    /// something, so there always is at least one
+    pub fn responses(&self, opponent: Move, outcome: Outcome) -> impl Iterator<Item = Move> + '_ {
+        self.moves().filter(move |&player| self.outcome(opponent, player) == outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{score_moves, score_outcomes, Scoring};
+
+    const RPS: &str = "rock 1\npaper 2\nscissors 3\npaper beats rock\nscissors beats paper\nrock beats scissors\n";
+
+    /// Line, found token and what was expected instead
+    fn rejection(config: &str) -> (usize, String, String) {
+        let e = Game::parse(config).unwrap_err();
+        (e.line, e.found, e.expected)
+    }
+
+    #[test]
+    fn rejects_a_move_listed_twice() {
+        let (line, found, expected) = rejection("rock 1\npaper 2\nrock 3\n");
+        assert_eq!((line, found.as_str(), expected.as_str()), (3, "rock", "a move not already listed"));
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert_eq!(rejection("rock\n").2, "a shape score or `beats`");
+        assert_eq!(rejection("rock one\n").2, "a shape score");
+        assert_eq!(rejection("rock 1\npaper 2\npaper beats\n").2, "the moves it beats");
+        assert_eq!(rejection("rock 1\npaper 2\npaper beats stone\n").1, "stone");
+        assert_eq!(rejection("rock 1\n").2, "at least two moves");
+        assert_eq!(rejection("# nothing but comments\n").2, "at least two moves");
+    }
+
+    #[test]
+    fn rejects_contradictory_rules() {
+        let (line, found, expected) = rejection(&format!("{}rock beats paper\n", RPS));
+        assert_eq!((line, found.as_str(), expected.as_str()), (7, "paper", "a move that rock can beat"));
+        assert_eq!(rejection(&format!("{}rock beats rock\n", RPS)).2, "a move that rock can beat");
+    }
+
+    #[test]
+    fn rejects_a_pairing_without_a_winner() {
+        let config = RPS.replace("rock beats scissors\n", "");
+        let (line, found, expected) = rejection(&config);
+        assert_eq!((line, found.as_str()), (3, "scissors"));
+        assert_eq!(expected, "`rock beats scissors` or `scissors beats rock`");
+    }
+
+    #[test]
+    fn rejects_moves_that_never_win_or_never_lose() {
+        let unbeaten = "rock 1\npaper 2\nscissors 3\nrock beats paper scissors\npaper beats scissors\n";
+        assert_eq!(rejection(unbeaten), (1, "rock".to_string(), "a move that both wins and loses against something".to_string()));
+        let winless = "rock 1\npaper 2\nscissors 3\npaper beats rock\nscissors beats rock paper\n";
+        assert_eq!(rejection(winless).1, "rock");
+    }
+
+    #[test]
+    fn rejects_more_moves_than_codes() {
+        let config = (0..=MAX_MOVES).map(|i| format!("m{} 1\n", i)).collect::<String>();
+        let (line, found, expected) = rejection(&config);
+        assert_eq!((line, found.as_str(), expected.as_str()), (14, "m13", "at most 13 moves"));
+    }
+
+    #[test]
+    fn rock_paper_scissors_file_matches_the_puzzle() {
+        let game = Game::rock_paper_scissors();
+        assert_eq!(game, Game::parse(RPS).unwrap());
+        assert_eq!(game.outcome(0, 1), Outcome::Win);
+        assert_eq!(game.outcome(2, 1), Outcome::Loss);
+        assert_eq!(game.outcome(1, 1), Outcome::Draw);
+    }
+
+    #[test]
+    fn scores_rock_paper_scissors_lizard_spock() {
+        let game = Game::parse(include_str!("../games/rpsls.txt")).unwrap();
+        assert_eq!(game.moves().map(|play| game.name(play)).collect::<Vec<_>>(), ["rock", "paper", "scissors", "lizard", "spock"]);
+        for play in game.moves() {
+            assert_eq!(game.responses(play, Outcome::Win).count(), 2);
+            assert_eq!(game.responses(play, Outcome::Loss).count(), 2);
+        }
+
+        // Rock loses to scissors, spock draws and scissors loses to lizard: 3 + 8 + 4. Aiming to
+        // lose to rock, win against spock and draw with scissors plays lizard, lizard, scissors.
+        let guide = "A X\nE Z\nC Y\n";
+        let scoring = Scoring::standard(&game);
+        assert_eq!(score_moves(&game, &scoring, guide), Ok(15));
+        assert_eq!(score_outcomes(&game, &scoring, guide), Ok(20));
+    }
+}
@@ -0,0 +1,260 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use common::ParseError;
+
+mod game;
+mod scoring;
+
+pub use game::{Game, Move, Outcome};
+pub use scoring::Scoring;
+
+type Lookup<'a, T> = HashMap<&'a str, T>;
+
+fn make_lookup<T: Copy>(codes: &[String], values: impl Iterator<Item = T>) -> Lookup<'_, T> {
+    codes.iter().map(String::as_str).zip(values).collect()
+}
+
+/// Lists a lookup's codes for error messages, e.g. "A, B or C"
+fn expected<T>(lookup: &Lookup<T>) -> String {
+    let mut codes = lookup.keys().copied().collect::<Vec<&str>>();
+    codes.sort();
+    let last = codes.pop().unwrap();
+    format!("{} or {}", codes.join(", "), last)
+}
+
+/// Splits a strategy guide line into its opponent and response codes
+fn split_line<'a>(strat_guide: &str, line: &'a str, expected: &str) -> Result<(&'a str, &'a str), ParseError> {
+    let mut parts = line.split(' ');
+    let opponent = parts.next().unwrap();
+    let response = parts
+        .next()
+        .ok_or_else(|| ParseError::after(strat_guide, line, format!("a space then {}", expected)))?;
+    Ok((opponent, response))
+}
+
+fn lookup<T: Copy>(lookup: &Lookup<T>, strat_guide: &str, code: &str) -> Result<T, ParseError> {
+    lookup.get(code).copied().ok_or_else(|| ParseError::at(strat_guide, code, expected(lookup)))
+}
+
+/// Total score when the second column is the move to play
+pub fn score_moves(game: &Game, scoring: &Scoring, strat_guide: &str) -> Result<u32, ParseError> {
+    let opponent_lookup = make_lookup(&scoring.opponent_codes, game.moves());
+    let player_lookup = make_lookup(&scoring.player_codes, game.moves());
+
+    strat_guide.lines().try_fold(0, |acc, line| {
+        let (opponent, player) = split_line(strat_guide, line, &expected(&player_lookup))?;
+        let opponent = lookup(&opponent_lookup, strat_guide, opponent)?;
+        let player = lookup(&player_lookup, strat_guide, player)?;
+        Ok(acc + encounter_score(game, scoring, opponent, player))
+    })
+}
+
+/// Total score when the second column is the outcome to aim for, playing the highest scoring
+/// move that gets it
+pub fn score_outcomes(game: &Game, scoring: &Scoring, strat_guide: &str) -> Result<u32, ParseError> {
+    let opponent_lookup = make_lookup(&scoring.opponent_codes, game.moves());
+    let outcome_lookup = make_lookup(&scoring.outcome_codes, Outcome::ALL.iter().copied());
+
+    strat_guide.lines().try_fold(0, |acc, line| {
+        let (opponent, outcome) = split_line(strat_guide, line, &expected(&outcome_lookup))?;
+        let opponent = lookup(&opponent_lookup, strat_guide, opponent)?;
+        let outcome = lookup(&outcome_lookup, strat_guide, outcome)?;
+        let player = game
+            .responses(opponent, outcome)
+            .max_by_key(|&player| (scoring.shape_points(player), Reverse(player)))
+            .unwrap();
+        Ok(acc + encounter_score(game, scoring, opponent, player))
+    })
+}
+
+/// What the strategy guide's second column is taken to mean
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Moves,
+    Outcomes,
+}
+
+/// One reading of the second column's codes and the total score it gives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretation {
+    pub column: Column,
+    /// Each code with the name of the move or outcome it is read as
+    pub meanings: Vec<(String, String)>,
+    pub score: u32,
+    /// Whether this is the reading `scoring` itself gives, i.e. the puzzle's own for that column
+    pub standard: bool,
+}
+
+/// Largest game `interpretations` will try every reading of: 7! = 5040 ways to assign the
+/// player codes, each scoring the whole guide, where 13 moves would be over six billion
+pub const MAX_INTERPRETED_MOVES: usize = 7;
+
+/// Every ordering of `0..n`, starting with `0..n` itself, made one at a time by Heap's algorithm
+struct Permutations {
+    perm: Vec<usize>,
+    /// Heap's algorithm's per-level swap counters
+    counters: Vec<usize>,
+    level: usize,
+    started: bool,
+}
+
+fn permutations(n: usize) -> Permutations {
+    Permutations { perm: (0..n).collect(), counters: vec![0; n], level: 1, started: false }
+}
+
+impl Iterator for Permutations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        if !self.started {
+            self.started = true;
+            return Some(self.perm.clone());
+        }
+        while self.level < self.perm.len() {
+            if self.counters[self.level] < self.level {
+                let swap = if self.level.is_multiple_of(2) { 0 } else { self.counters[self.level] };
+                self.perm.swap(swap, self.level);
+                self.counters[self.level] += 1;
+                self.level = 1;
+                return Some(self.perm.clone());
+            }
+            self.counters[self.level] = 0;
+            self.level += 1;
+        }
+        None
+    }
+}
+
+/// Scores the guide under every way of assigning the player codes to moves and the outcome
+/// codes to outcomes, best first
+///
+/// # Panics
+///
+/// If the game has more than `MAX_INTERPRETED_MOVES` moves, as there would be too many
+/// readings to try.
+pub fn interpretations(game: &Game, scoring: &Scoring, strat_guide: &str) -> Result<Vec<Interpretation>, ParseError> {
+    assert!(game.moves().len() <= MAX_INTERPRETED_MOVES, "too many moves to try every reading");
+    let mut readings = vec![];
+    for perm in permutations(game.moves().len()) {
+        let mut reading = scoring.clone();
+        for (code, &play) in scoring.player_codes.iter().zip(&perm) {
+            reading.player_codes[play] = code.clone();
+        }
+        readings.push(Interpretation {
+            column: Column::Moves,
+            meanings: scoring
+                .player_codes
+                .iter()
+                .zip(&perm)
+                .map(|(code, &play)| (code.clone(), game.name(play).to_string()))
+                .collect(),
+            score: score_moves(game, &reading, strat_guide)?,
+            standard: reading == *scoring,
+        });
+    }
+    for perm in permutations(3) {
+        let mut reading = scoring.clone();
+        for (code, &outcome) in scoring.outcome_codes.iter().zip(&perm) {
+            reading.outcome_codes[outcome] = code.clone();
+        }
+        readings.push(Interpretation {
+            column: Column::Outcomes,
+            meanings: scoring
+                .outcome_codes
+                .iter()
+                .zip(&perm)
+                .map(|(code, &outcome)| (code.clone(), Outcome::ALL[outcome].name().to_string()))
+                .collect(),
+            score: score_outcomes(game, &reading, strat_guide)?,
+            standard: reading == *scoring,
+        });
+    }
+    readings.sort_by_key(|reading| Reverse(reading.score));
+    Ok(readings)
+}
+
+/// Lays out interpretations as a table of rank, score and what each code means, marking the
+/// puzzle's own two readings
+pub fn render_interpretations(readings: &[Interpretation]) -> String {
+    let mut table = format!("{:>4}  {:>8}  {:<8}  meaning\n", "rank", "score", "column");
+    for (rank, reading) in readings.iter().enumerate() {
+        let column = match reading.column {
+            Column::Moves => "moves",
+            Column::Outcomes => "outcomes",
+        };
+        let meanings = reading.meanings.iter().map(|(code, meaning)| format!("{}={}", code, meaning)).collect::<Vec<String>>();
+        let standard = match (reading.standard, reading.column) {
+            (true, Column::Moves) => "  (part 1)",
+            (true, Column::Outcomes) => "  (part 2)",
+            (false, _) => "",
+        };
+        table += &format!("{:>4}  {:>8}  {:<8}  {}{}\n", rank + 1, reading.score, column, meanings.join(" "), standard);
+    }
+    table
+}
+
+pub fn solve_part1(strat_guide: &str) -> Result<u32, ParseError> {
+    let game = Game::rock_paper_scissors();
+    score_moves(&game, &Scoring::standard(&game), strat_guide)
+}
+
+pub fn solve_part2(strat_guide: &str) -> Result<u32, ParseError> {
+    let game = Game::rock_paper_scissors();
+    score_outcomes(&game, &Scoring::standard(&game), strat_guide)
+}
+
+fn encounter_score(game: &Game, scoring: &Scoring, opponent: Move, player: Move) -> u32 {
+    scoring.outcome_points(game.outcome(opponent, player)) + scoring.shape_points(player)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn permutations_are_every_ordering_once() {
+        for n in 0..=5 {
+            let perms = permutations(n).collect::<Vec<Vec<usize>>>();
+            assert_eq!(perms[0], (0..n).collect::<Vec<usize>>());
+            assert_eq!(perms.len(), (1..=n).product::<usize>());
+            let mut sorted = perms.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(sorted.len(), perms.len());
+        }
+    }
+
+    #[test]
+    fn example_has_twelve_readings_including_both_parts() {
+        let game = Game::rock_paper_scissors();
+        let readings = interpretations(&game, &Scoring::standard(&game), EXAMPLE).unwrap();
+        assert_eq!(readings.len(), 12);
+        assert!(readings.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+        let standard = |column| readings.iter().filter(|r| r.standard && r.column == column).collect::<Vec<_>>();
+        assert_eq!(standard(Column::Moves).len(), 1);
+        assert_eq!(standard(Column::Moves)[0].score, 15);
+        assert_eq!(standard(Column::Outcomes).len(), 1);
+        assert_eq!(standard(Column::Outcomes)[0].score, 12);
+
+        let table = render_interpretations(&readings);
+        assert!(table.lines().any(|line| line.contains(" 15 ") && line.ends_with("(part 1)")));
+        assert!(table.lines().any(|line| line.contains(" 12 ") && line.ends_with("(part 2)")));
+    }
+
+    #[test]
+    #[should_panic(expected = "too many moves")]
+    fn refuses_games_too_big_to_enumerate() {
+        // Each move beats the three after it round the circle, and the one opposite if it comes first
+        let n = MAX_INTERPRETED_MOVES + 1;
+        let mut config = (0..n).map(|i| format!("m{} {}\n", i, i + 1)).collect::<String>();
+        for i in 0..n {
+            let beaten = (1..n).filter(|d| *d < n / 2 || (*d == n / 2 && i < n / 2)).map(|d| format!(" m{}", (i + d) % n));
+            config += &format!("m{} beats{}\n", i, beaten.collect::<String>());
+        }
+        let game = Game::parse(&config).unwrap();
+        let _ = interpretations(&game, &Scoring::standard(&game), "A Z\n");
+    }
+}
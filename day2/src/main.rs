@@ -1,82 +1,58 @@
-use std::collections::HashMap;
 use std::env;
-use std::fs;
+use std::process;
 
-#[derive(PartialEq, Clone)]
-enum Play {
-    Rock,
-    Paper,
-    Scissors,
-}
-
-type Lookup<'a> = HashMap<&'a str, Play>;
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    assert_eq!(args.len(), 2, "Need to provide an input file as a second argument. \
-                               Number of arguments is not 2");
-
-    let strat_guide = fs::read_to_string(&args[1]).unwrap();
-
-    let opponent_lookup =
-        Lookup::from([("A", Play::Rock), ("B", Play::Paper), ("C", Play::Scissors)]);
+use common::{init_tracing, input_name, read_input, run_inputs, take_verbosity, ParseError};
+use day2::{Game, Scoring};
 
-    let player_lookup =
-        Lookup::from([("X", Play::Rock), ("Y", Play::Paper), ("Z", Play::Scissors)]);
-
-    let total_score = strat_guide.lines().fold(0, |acc, line| {
-        let mut parts = line.split(' ');
-        let opponent = opponent_lookup.get(parts.next().unwrap()).unwrap();
-        let player = player_lookup.get(parts.next().unwrap()).unwrap();
-        acc + encounter_score(opponent, player)
-    });
-
-    println!("part 1 score: {}", total_score);
-
-    let total_score2 = strat_guide.lines().fold(0, |acc, line| {
-        let mut parts = line.split(' ');
-        let opponent = opponent_lookup.get(parts.next().unwrap()).unwrap();
-        let player = &get_play(opponent, parts.next().unwrap());
-        acc + encounter_score(opponent, player)
-    });
-
-    println!("part 2 score: {}", total_score2)
-}
-
-fn get_win(vs: &Play) -> Play {
-    match vs {
-        Play::Rock => Play::Paper,
-        Play::Paper => Play::Scissors,
-        Play::Scissors => Play::Rock,
+fn run(input: &str, game: &Game, scoring: &Scoring, interpretations: bool) -> Result<(), ParseError> {
+    if interpretations {
+        print!("{}", day2::render_interpretations(&day2::interpretations(game, scoring, input)?));
+    } else {
+        println!("part 1 score: {}", day2::score_moves(game, scoring, input)?);
+        println!("part 2 score: {}", day2::score_outcomes(game, scoring, input)?);
     }
+    Ok(())
 }
 
-fn get_loss(vs: &Play) -> Play {
-    match vs {
-        Play::Rock => Play::Scissors,
-        Play::Paper => Play::Rock,
-        Play::Scissors => Play::Paper,
+/// Removes `flag FILE` from the arguments and parses that file, if the flag is there
+fn take_config<T>(
+    args: &mut Vec<String>,
+    flag: &str,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<Option<T>, String> {
+    let Some(i) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+    args.remove(i);
+    if i == args.len() {
+        return Err(format!("{} needs a file", flag));
     }
+    let path = args.remove(i);
+    let config = read_input(&path).map_err(|e| format!("Could not read {}: {}", input_name(&path), e))?;
+    parse(&config).map(Some).map_err(|e| e.with_file(input_name(&path)).to_string())
 }
 
-fn get_play(opponent: &Play, code: &str) -> Play {
-    match code {
-        "X" => get_loss(opponent),
-        "Y" => opponent.clone(),
-        "Z" => get_win(opponent),
-        _ => panic!(),
-    }
+/// Loads `--game FILE`, or rock paper scissors, then `--scoring FILE` on top of the standard rules
+fn take_rules(args: &mut Vec<String>) -> Result<(Game, Scoring), String> {
+    let game = take_config(args, "--game", Game::parse)?.unwrap_or_else(Game::rock_paper_scissors);
+    let scoring = take_config(args, "--scoring", |config| Scoring::parse(config, &game))?
+        .unwrap_or_else(|| Scoring::standard(&game));
+    Ok((game, scoring))
 }
 
-fn encounter_score(opponent: &Play, player: &Play) -> u32 {
-    let is_victory = player == &get_win(opponent);
-    let is_draw = opponent == player;
-    let outcome_score = if is_victory { 6 } else if is_draw { 3 } else { 0 };
-
-    let shape_score = match player {
-        Play::Rock => 1,
-        Play::Paper => 2,
-        Play::Scissors => 3,
-    };
-    outcome_score + shape_score
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    init_tracing(take_verbosity(&mut args), "day2");
+    let (game, scoring) = take_rules(&mut args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    let before = args.len();
+    args.retain(|arg| arg != "--interpretations");
+    let interpretations = args.len() < before;
+    if interpretations && game.moves().len() > day2::MAX_INTERPRETED_MOVES {
+        eprintln!("--interpretations tries every reading, so it takes games of at most {} moves", day2::MAX_INTERPRETED_MOVES);
+        process::exit(1);
+    }
+    run_inputs(&args, |input| run(input, &game, &scoring, interpretations));
 }
@@ -0,0 +1,194 @@
+//! Scoring rules: points per outcome and per shape, and the codes a strategy guide uses
+//!
+//! A scoring file changes the standard rules one line at a time; blank lines and lines
+//! starting with `#` are ignored.
+//!
+//! ```text
+//! outcome 0 3 6     points for a loss, a draw and a win
+//! shape rock 1      points for playing rock
+//! opponent A B C    codes for the opponent's moves, in the game's order
+//! player X Y Z      codes for the player's moves, in the game's order
+//! result X Y Z      codes for aiming to lose, draw and win
+//! ```
+
+use common::{parse_at, ParseError};
+
+use crate::{Game, Move, Outcome};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoring {
+    /// Points for a loss, a draw and a win
+    pub outcome_points: [u32; 3],
+    /// Points for each move, in the game's order
+    pub shape_points: Vec<u32>,
+    pub opponent_codes: Vec<String>,
+    pub player_codes: Vec<String>,
+    /// Codes for aiming to lose, draw and win
+    pub outcome_codes: [String; 3],
+}
+
+impl Scoring {
+    /// The puzzle's rules: 0, 3 and 6 for the outcomes, the game's shape scores, opponent codes
+    /// counting up from A, player codes counting down to Z, and X, Y and Z for the outcomes
+    pub fn standard(game: &Game) -> Scoring {
+        let moves = game.moves().len() as u8;
+        let letters = |first: u8| (0..moves).map(|i| ((first + i) as char).to_string()).collect();
+        Scoring {
+            outcome_points: [0, 3, 6],
+            shape_points: game.moves().map(|play| game.score(play)).collect(),
+            opponent_codes: letters(b'A'),
+            player_codes: letters(b'Z' + 1 - moves),
+            outcome_codes: ["X".to_string(), "Y".to_string(), "Z".to_string()],
+        }
+    }
+
+    /// Reads a scoring file on top of the standard rules for `game`
+    pub fn parse(config: &str, game: &Game) -> Result<Scoring, ParseError> {
+        let mut scoring = Scoring::standard(game);
+        let lines = config.lines().filter(|line| !line.trim().is_empty() && !line.starts_with('#'));
+
+        for line in lines {
+            let mut words = line.split_whitespace();
+            let key = words.next().unwrap();
+            let values = words.collect::<Vec<&str>>();
+            let count = |n: usize| {
+                if values.len() == n {
+                    Ok(())
+                } else {
+                    let found = values.get(n).copied().unwrap_or(&line[line.len()..]);
+                    Err(ParseError::at(config, found, format!("{} values after `{}`", n, key)))
+                }
+            };
+            match key {
+                "outcome" => {
+                    count(3)?;
+                    for (points, value) in scoring.outcome_points.iter_mut().zip(&values) {
+                        *points = parse_at(config, value, "points for an outcome")?;
+                    }
+                }
+                "shape" => {
+                    count(2)?;
+                    let play = game
+                        .moves()
+                        .find(|&play| game.name(play) == values[0])
+                        .ok_or_else(|| ParseError::at(config, values[0], "a move of the game"))?;
+                    scoring.shape_points[play] = parse_at(config, values[1], "points for a shape")?;
+                }
+                "opponent" | "player" | "result" => {
+                    let n = if key == "result" { 3 } else { game.moves().len() };
+                    count(n)?;
+                    if let Some(i) = (1..n).find(|&i| values[..i].contains(&values[i])) {
+                        return Err(ParseError::at(config, values[i], "a code not already used"));
+                    }
+                    let codes = match key {
+                        "opponent" => &mut scoring.opponent_codes[..],
+                        "player" => &mut scoring.player_codes[..],
+                        _ => &mut scoring.outcome_codes[..],
+                    };
+                    for (code, value) in codes.iter_mut().zip(&values) {
+                        *code = value.to_string();
+                    }
+                }
+                _ => return Err(ParseError::at(config, key, "outcome, shape, opponent, player or result")),
+            }
+        }
+        Ok(scoring)
+    }
+
+    pub fn outcome_points(&self, outcome: Outcome) -> u32 {
+        self.outcome_points[outcome as usize]
+    }
+
+    pub fn shape_points(&self, play: Move) -> u32 {
+        self.shape_points[play]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{score_moves, score_outcomes};
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    fn rps_with(config: &str) -> (Game, Scoring) {
+        let game = Game::rock_paper_scissors();
+        let scoring = Scoring::parse(config, &game).unwrap();
+        (game, scoring)
+    }
+
+    fn part1(config: &str) -> u32 {
+        let (game, scoring) = rps_with(config);
+        score_moves(&game, &scoring, EXAMPLE).unwrap()
+    }
+
+    fn rejection(config: &str) -> (usize, usize, String, String) {
+        let e = Scoring::parse(config, &Game::rock_paper_scissors()).unwrap_err();
+        (e.line, e.column, e.found, e.expected)
+    }
+
+    #[test]
+    fn empty_file_is_the_standard_rules() {
+        let (game, scoring) = rps_with("# nothing to change\n\n");
+        assert_eq!(scoring, Scoring::standard(&game));
+        assert_eq!(part1(""), 15);
+    }
+
+    #[test]
+    fn outcome_points_override() {
+        // Paper beating rock is worth 0 and rock losing to paper 6, which happens to balance out
+        assert_eq!(part1("outcome 6 3 0\n"), 2 + 7 + 6);
+        assert_eq!(part1("outcome 0 3 10\n"), 12 + 1 + 6);
+        let (game, scoring) = rps_with("outcome 6 3 0\n");
+        assert_eq!(scoring.outcome_points(Outcome::Loss), 6);
+        assert_eq!(score_outcomes(&game, &scoring, EXAMPLE), Ok(4 + 7 + 1));
+    }
+
+    #[test]
+    fn shape_points_override() {
+        assert_eq!(part1("shape paper 10\n"), 16 + 1 + 6);
+        let (_, scoring) = rps_with("shape paper 10\nshape paper 20\n");
+        assert_eq!(scoring.shape_points, vec![1, 20, 3]);
+    }
+
+    #[test]
+    fn letter_codes_override() {
+        let (game, scoring) = rps_with("opponent R P S\nplayer 1 2 3\nresult L D W\n");
+        assert_eq!(score_moves(&game, &scoring, "R 2\nP 1\nS 3\n"), Ok(15));
+        assert_eq!(score_outcomes(&game, &scoring, "R D\nP L\nS W\n"), Ok(12));
+        let e = score_moves(&game, &scoring, "A Y\n").unwrap_err();
+        assert_eq!((e.found.as_str(), e.expected.as_str()), ("A", "P, R or S"));
+    }
+
+    #[test]
+    fn standard_codes_follow_the_number_of_moves() {
+        let game = Game::parse(include_str!("../games/rpsls.txt")).unwrap();
+        let scoring = Scoring::standard(&game);
+        assert_eq!(scoring.opponent_codes, ["A", "B", "C", "D", "E"]);
+        assert_eq!(scoring.player_codes, ["V", "W", "X", "Y", "Z"]);
+        assert_eq!(scoring.outcome_codes, ["X", "Y", "Z"]);
+        assert_eq!(scoring.shape_points, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn rejects_the_wrong_number_of_values() {
+        assert_eq!(rejection("outcome 1 2\n"), (1, 12, String::new(), "3 values after `outcome`".to_string()));
+        assert_eq!(rejection("outcome 1 2 3 4\n").2, "4");
+        assert_eq!(rejection("player X Y\n").3, "3 values after `player`");
+        assert_eq!(rejection("shape rock\n").3, "2 values after `shape`");
+    }
+
+    #[test]
+    fn rejects_repeated_codes() {
+        assert_eq!(rejection("result L L W\n"), (1, 10, "L".to_string(), "a code not already used".to_string()));
+        assert_eq!(rejection("opponent A B A\n").1, 14);
+    }
+
+    #[test]
+    fn rejects_unknown_keys_moves_and_points() {
+        assert_eq!(rejection("colour red\n").3, "outcome, shape, opponent, player or result");
+        assert_eq!(rejection("shape stone 1\n").2, "stone");
+        assert_eq!(rejection("shape rock lots\n").3, "points for a shape");
+        assert_eq!(rejection("outcome 0 3 -6\n").3, "points for an outcome");
+    }
+}
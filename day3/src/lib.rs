@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+use std::iter::zip;
+use itertools::Itertools;
+use common::ParseError;
+
+fn priority_map() -> HashMap<char, u32> {
+    zip('a'..='z', 1..=26)
+        .chain(zip('A'..='Z', 27..=52))
+        .collect()
+}
+
+/// Rucksacks may only hold items a-z and A-Z, so every char is also a single byte
+fn check_items(input: &str, line: &str) -> Result<(), ParseError> {
+    match line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        Some((i, c)) => Err(ParseError::at(input, &line[i..i + c.len_utf8()], "an item a-z or A-Z")),
+        None => Ok(()),
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<u32, ParseError> {
+    let priority_map = priority_map();
+
+    input
+        .lines()
+        .map(|line| {
+            check_items(input, line)?;
+            let (first, last) = line.split_at(line.len() / 2);
+            match first.chars().find(|&c| last.contains(c)) {
+                Some(found) => Ok(priority_map[&found]),
+                None => Err(ParseError::at(input, line, "an item shared by both compartments")),
+            }
+        }).sum::<Result<u32, ParseError>>()
+}
+
+pub fn solve_part2(input: &str) -> Result<u32, ParseError> {
+    let priority_map = priority_map();
+
+    let mut sum = 0;
+    for chunk in &input.lines().chunks(3) {
+        let mut group = chunk.collect::<Vec<&str>>();
+        for line in &group {
+            check_items(input, line)?;
+        }
+        if group.len() < 3 {
+            return Err(ParseError::after(input, group.last().unwrap(), "a group of three rucksacks"));
+        }
+        group.sort_by_key(|bag| bag.len());
+        match group[0].chars().find(|&c| group[1].contains(c) && group[2].contains(c)) {
+            Some(found) => sum += priority_map[&found],
+            None => return Err(ParseError::at(input, group[0], "an item shared by the whole group")),
+        }
+    }
+    Ok(sum)
+}
@@ -0,0 +1,47 @@
+use common::{parse_at, ParseError};
+
+type ElfPair = Vec<Vec<u32>>;
+
+fn parse_line(input: &str, line: &str) -> Result<ElfPair, ParseError> {
+    let elf_pair = line.split(',')
+        .map(|elf| {
+            let range = elf.split('-')
+                .map(|x| parse_at::<u32>(input, x, "a section number"))
+                .collect::<Result<Vec<u32>, _>>()?;
+            match range.len() {
+                2 => Ok(range),
+                1 => Err(ParseError::after(input, elf, "`-` then a section number")),
+                _ => Err(ParseError::at(input, elf, "a single range of sections")),
+            }
+        })
+        .collect::<Result<ElfPair, _>>()?;
+    match elf_pair.len() {
+        2 => Ok(elf_pair),
+        1 => Err(ParseError::after(input, line, "`,` then a second range")),
+        _ => Err(ParseError::at(input, line, "exactly two ranges")),
+    }
+}
+
+fn complete_overlap(elf_pair: &ElfPair) -> bool {
+    (elf_pair[0][0] <= elf_pair[1][0] && elf_pair[0][1] >= elf_pair[1][1])
+        || (elf_pair[1][0] <= elf_pair[0][0] && elf_pair[1][1] >= elf_pair[0][1])
+}
+
+fn partial_overlap(elf_pair: &ElfPair) -> bool {
+    (elf_pair[1][0]..=elf_pair[1][1]).contains(&elf_pair[0][0])
+        || (elf_pair[1][0]..=elf_pair[1][1]).contains(&elf_pair[0][1])
+        || (elf_pair[0][0]..=elf_pair[0][1]).contains(&elf_pair[1][0])
+        || (elf_pair[0][0]..=elf_pair[0][1]).contains(&elf_pair[1][1])
+}
+
+fn count_overlaps(input: &str, overlaps: fn(&ElfPair) -> bool) -> Result<u32, ParseError> {
+    input.lines().try_fold(0, |acc, line| Ok(acc + u32::from(overlaps(&parse_line(input, line)?))))
+}
+
+pub fn solve_part1(input: &str) -> Result<u32, ParseError> {
+    count_overlaps(input, complete_overlap)
+}
+
+pub fn solve_part2(input: &str) -> Result<u32, ParseError> {
+    count_overlaps(input, partial_overlap)
+}
@@ -0,0 +1,171 @@
+use common::{parse_at, ParseError};
+
+/// Move `mv` crates from stack `from` to stack `to`, both counted from 0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub mv: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Crates on each stack, from the bottom up
+pub type Stacks = Vec<Vec<String>>;
+
+/// Splits a diagram row into its 4 character wide columns, e.g. "[A] "
+fn columns(line: &str) -> Vec<&str> {
+    let bounds = line
+        .char_indices()
+        .map(|(i, _)| i)
+        .step_by(4)
+        .chain([line.len()])
+        .collect::<Vec<_>>();
+    bounds.windows(2).map(|w| &line[w[0]..w[1]]).collect()
+}
+
+pub fn parse(input: &str) -> Result<(Stacks, Vec<Instruction>), ParseError> {
+    let diagram = input
+        .lines()
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<&str>>();
+
+    let mut split = diagram
+        .iter()
+        .map(|line| columns(line))
+        .collect::<Vec<_>>();
+
+    let numbers = split
+        .pop()
+        .ok_or_else(|| ParseError::at(input, &input[..0], "a crate diagram"))?;
+    let last_number = numbers
+        .last()
+        .map(|number| number.trim())
+        .ok_or_else(|| ParseError::at(input, diagram[diagram.len() - 1], "a row of stack numbers"))?;
+    let expected = format!("{} as the last stack number", numbers.len());
+    if parse_at::<usize>(input, last_number, &expected)? != numbers.len() {
+        return Err(ParseError::at(input, last_number, expected));
+    }
+    let mut stacks = vec![Vec::<String>::new(); numbers.len()];
+
+    for row in split.iter() {
+        for (i, col) in row.iter().enumerate() {
+            let cell = col.strip_suffix(' ').unwrap_or(col);
+            if cell.trim().is_empty() {
+                continue;
+            }
+            let crate_ = match cell.strip_prefix('[').and_then(|cell| cell.strip_suffix(']')) {
+                Some(crate_) if crate_.chars().count() == 1 && crate_.chars().all(char::is_alphabetic) => crate_.to_string(),
+                _ => return Err(ParseError::at(input, cell, "a crate like `[A]` or an empty space")),
+            };
+            if i >= stacks.len() {
+                return Err(ParseError::at(input, col, format!("at most {} stacks", stacks.len())));
+            }
+            stacks[i].insert(0, crate_);
+        }
+    }
+
+    // Both cranes move the same number of crates between the same stacks, so the moves can be
+    // checked against the stack heights up front
+    let mut heights = stacks.iter().map(|stack| stack.len()).collect::<Vec<_>>();
+    let stack_number = |token: &str| -> Result<usize, ParseError> {
+        let expected = format!("a stack number from 1 to {}", stacks.len());
+        match parse_at::<usize>(input, token, &expected)? {
+            n @ 1.. if n <= stacks.len() => Ok(n - 1),
+            _ => Err(ParseError::at(input, token, expected)),
+        }
+    };
+
+    let instructions = input
+        .lines()
+        .skip_while(|line| !line.is_empty())
+        .skip(1)
+        .map(|line| {
+            let splits = line.split(' ').collect::<Vec<_>>();
+            let token = |i: usize, expected: &str| {
+                splits.get(i).copied().ok_or_else(|| ParseError::after(input, line, expected))
+            };
+            let keyword = |i: usize, keyword: &str| {
+                let expected = format!("`{}`", keyword);
+                match token(i, &expected)? {
+                    found if found == keyword => Ok(()),
+                    found => Err(ParseError::at(input, found, expected)),
+                }
+            };
+            keyword(0, "move")?;
+            let mv_token = token(1, "a number of crates")?;
+            keyword(2, "from")?;
+            let from = stack_number(token(3, "a stack number")?)?;
+            keyword(4, "to")?;
+            let to = stack_number(token(5, "a stack number")?)?;
+            if let Some(extra) = splits.get(6) {
+                return Err(ParseError::at(input, extra, "end of line"));
+            }
+
+            let mv = parse_at::<usize>(input, mv_token, "a number of crates")?;
+            if mv > heights[from] {
+                return Err(ParseError::at(
+                    input,
+                    mv_token,
+                    format!("at most the {} crates on stack {}", heights[from], from + 1),
+                ));
+            }
+            heights[from] -= mv;
+            heights[to] += mv;
+
+            Ok(Instruction { mv, from, to })
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok((stacks, instructions))
+}
+
+/// Writes a crate diagram and its moves back out in the puzzle's layout
+pub fn render(stacks: &Stacks, instructions: &[Instruction]) -> String {
+    let mut out = String::new();
+    let tallest = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    for level in (0..tallest).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| stack.get(level).map_or("   ".to_string(), |crate_| format!("[{}]", crate_)))
+            .collect::<Vec<_>>();
+        out += &row.join(" ");
+        out.push('\n');
+    }
+    let numbers = (1..=stacks.len()).map(|n| format!("{:^3}", n)).collect::<Vec<_>>();
+    out += &numbers.join(" ");
+    out += "\n\n";
+    for cmd in instructions {
+        out += &format!("move {} from {} to {}\n", cmd.mv, cmd.from + 1, cmd.to + 1);
+    }
+    out
+}
+
+fn top_crates(stacks: &Stacks) -> String {
+    stacks
+        .iter()
+        .filter_map(|stack| stack.last().cloned())
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    let (mut stacks, instructions) = parse(input)?;
+    instructions.iter().for_each(|cmd| {
+        for _ in 0..cmd.mv {
+            let to_move = stacks[cmd.from].pop().unwrap();
+            stacks[cmd.to].push(to_move);
+        }
+    });
+
+    Ok(top_crates(&stacks))
+}
+
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    let (mut stacks, instructions) = parse(input)?;
+    instructions.iter().for_each(|cmd| {
+        let idx = stacks[cmd.from].len() - cmd.mv;
+        let mut to_move = stacks[cmd.from].split_off(idx);
+        stacks[cmd.to].append(&mut to_move);
+    });
+
+    Ok(top_crates(&stacks))
+}
@@ -0,0 +1,25 @@
+//! The CrateMover 9000 moves crates one at a time while the 9001 moves them in one go with
+//! `split_off`, so the 9000 should match a 9001 that is only ever asked to move single crates.
+
+use proptest::prelude::*;
+
+/// Rewrites every move of n crates as n moves of one crate
+fn one_at_a_time(input: &str) -> String {
+    let (stacks, instructions) = day5::parse(input).unwrap();
+    let single = instructions
+        .iter()
+        .flat_map(|cmd| (0..cmd.mv).map(move |_| day5::Instruction { mv: 1, ..cmd.clone() }))
+        .collect::<Vec<_>>();
+    day5::render(&stacks, &single)
+}
+
+proptest! {
+    #[test]
+    fn crate_mover_9000_is_9001_moving_single_crates(seed in any::<u64>(), moves in 0usize..60) {
+        let input = inputgen::generate(5, seed, moves).unwrap();
+        let single = one_at_a_time(&input);
+
+        prop_assert_eq!(day5::solve_part1(&input).unwrap(), day5::solve_part2(&single).unwrap());
+        prop_assert_eq!(day5::solve_part1(&input).unwrap(), day5::solve_part1(&single).unwrap());
+    }
+}
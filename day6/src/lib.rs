@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+use common::ParseError;
+
+/// Position just after the first run of `sequence_length` distinct letters in the datastream,
+/// which is the input's first line
+fn find_start(input: &str, sequence_length: usize) -> Result<usize, ParseError> {
+    let line = input.lines().next().unwrap_or(&input[..0]);
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+        return Err(ParseError::at(input, &line[i..i + c.len_utf8()], "a letter a-z"));
+    }
+    let chars = line.chars().collect::<Vec<char>>();
+    let search = chars
+        .windows(sequence_length)
+        .enumerate()
+        .find(|&(_i, block)| block.iter().collect::<HashSet<_>>().len() == sequence_length);
+
+    if let Some((idx, _)) = search {
+        Ok(idx + sequence_length)
+    }
+    else {
+        Err(ParseError::after(input, line, format!("a marker of {} distinct characters", sequence_length)))
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    find_start(input, 4)
+}
+
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    find_start(input, 14)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newline_is_not_part_of_a_marker() {
+        let e = solve_part1("abc\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.expected.as_str()), (1, 4, "a marker of 4 distinct characters"));
+        assert_eq!(solve_part1("abcd\n"), Ok(4));
+        assert!(solve_part1("").is_err());
+    }
+
+    #[test]
+    fn rejects_anything_but_lowercase_letters() {
+        let e = solve_part1("abcD\n").unwrap_err();
+        assert_eq!((e.column, e.found.as_str()), (4, "D"));
+        assert_eq!(solve_part2("ab cd\n").unwrap_err().found, " ");
+    }
+}
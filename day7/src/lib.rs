@@ -0,0 +1,134 @@
+use common::{parse_at, ParseError};
+use std::ops::{Index, IndexMut};
+use tracing::trace;
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    size: u64,
+    parent: Option<usize>,
+    children: Vec<usize>,
+}
+
+impl Node {
+    fn new(name: &str, size: u64) -> Self {
+        Self {
+            name: String::from(name),
+            size,
+            parent: None,
+            children: vec![]
+        }
+    }
+}
+
+
+struct Disk {
+    disk: Vec<Node>,
+}
+
+impl Disk {
+    fn new() -> Self {
+        Self {
+            disk: vec![Node::new("/", 0)]
+        }
+    }
+
+    fn add(&mut self, parent: usize, name: &str, size: u64) -> usize {
+        let idx = self.disk.len(); 
+        self.disk.push(Node::new(name, size));
+        self.disk[idx].parent = Some(parent);
+        self.disk[parent].children.push(idx);
+        self.disk[parent].size += size;
+
+        let mut cwd = parent;
+        while let Some(parent_) = self.disk[cwd].parent {
+            self.disk[parent_].size += size;
+            cwd = parent_;
+        }
+
+        idx
+    }
+}
+
+impl Index<usize> for Disk {
+    type Output = Node;
+    fn index(& self, i: usize) -> &Node {
+        &self.disk[i]
+    }
+}
+
+impl IndexMut<usize> for Disk {
+    fn index_mut(&mut self, i: usize) -> &mut Node {
+        &mut self.disk[i]
+    }
+}
+
+fn parse_disk(input: &str) -> Result<Disk, ParseError> {
+    let mut cwd = 0;
+    let mut disk = Disk::new();
+    
+    for line in input.lines() {
+        let parts = line.split(' ').collect::<Vec<&str>>();
+        let part = |i: usize, expected: &str| {
+            parts.get(i).copied().ok_or_else(|| ParseError::after(input, line, expected))
+        };
+        if parts[0] == "$" { // command
+            match part(1, "a command")? {
+                "cd" => match part(2, "a directory name")? {
+                    "/" => cwd = 0,
+                    ".." => match disk[cwd].parent {
+                        Some(parent) => cwd = parent,
+                        None => return Err(ParseError::at(input, parts[2], "a directory below `/` to leave")),
+                    },
+                    dir => match disk[cwd].children.iter().find(|&idx| disk[*idx].name == dir) {
+                        Some(&child) => cwd = child,
+                        None => return Err(ParseError::at(input, dir, "a directory listed by `ls`")),
+                    }
+                },
+                "ls" => (),
+                command => return Err(ParseError::at(input, command, "`cd` or `ls`")),
+            }
+        }
+        else { // listing
+            let name = part(1, "a space then a name")?;
+            match parts[0] {
+                "dir" => {
+                    disk.add(cwd, name, 0);
+                },
+                size => {
+                    disk.add(cwd, name, parse_at(input, size, "a file size or `dir`")?);
+                }
+            }
+        }
+    }
+    trace!("{:?}", disk.disk);
+    Ok(disk)
+}
+
+pub fn solve_part1(input: &str) -> Result<u64, ParseError> {
+    let disk = parse_disk(input)?;
+
+    Ok(disk.disk
+        .iter()
+        .filter(|node| node.size <= 100000 && !node.children.is_empty())
+        .fold(0, |acc, node| acc + node.size))
+}
+
+pub fn solve_part2(input: &str) -> Result<u64, ParseError> {
+    let disk = parse_disk(input)?;
+    
+    let free_space = 70000000u64.saturating_sub(disk[0].size);
+    let additional_required_free_space = 30000000u64.saturating_sub(free_space);
+
+    let mut dir_sizes = disk.disk
+        .iter()
+        .filter(|node| !node.children.is_empty())
+        .map(|node| node.size)
+        .collect::<Vec<u64>>();
+    dir_sizes.sort();
+
+    dir_sizes
+        .into_iter()
+        .find(|size| size >= &additional_required_free_space)
+        .ok_or_else(|| ParseError::after(input, input, "a directory listing"))
+}
@@ -0,0 +1,155 @@
+use common::ParseError;
+use grid::{Coord, Grid};
+use tracing::{debug, trace};
+
+/// Height of every tree, 0 to 9
+pub fn tree_heights(input: &str) -> Result<Grid<i32>, ParseError> {
+    Grid::parse(input, "a tree height 0-9", |c| c.to_digit(10).map(|h| h as i32))
+}
+
+fn get_scenic_score(grid: &Grid<i32>, coord: Coord) -> usize {
+    let col = grid.col_at(coord.0);
+    let row = grid.row_at(coord.1);        
+    let height = grid[coord];
+
+    trace!("height at {:?}: {}", coord, height);
+    let to_right = if let Some((n, _)) = row[coord.0..].iter().skip(1).enumerate().find(|(_, &h)| h >= height) {
+        n + 1
+    }
+    else {
+        row[coord.0..].iter().skip(1).len()
+    };
+
+    let to_left = if let Some((n, _)) = row[0..coord.0].iter().rev().enumerate().find(|(_, &h)| h >= height) {
+        n + 1
+    }
+    else {
+        row[0..coord.0].iter().len()
+    };
+
+
+
+    let to_down = if let Some((n, _)) = col[coord.1..].iter().skip(1).enumerate().find(|(_, &h)| *h >= height) {
+        n + 1
+    }
+    else {
+        col[coord.1..].iter().skip(1).len()
+    };
+
+    let to_up = if let Some((n, _)) = col[0..coord.1].iter().rev().enumerate().find(|(_, &h)| *h >= height) {
+        n + 1
+    }
+    else {
+        col[..coord.1].len()
+    };
+
+    trace!("Score for {:?} = {} * {} * {} * {}", coord, to_up, to_left, to_down, to_right);
+
+    to_up * to_left * to_down * to_right
+}
+
+
+fn render_heights(grid: &Grid<i32>) -> String {
+    grid.render(|c| format!("[{:^5}]", c))
+}
+
+fn render_visibility(grid: &Grid<bool>) -> String {
+    grid.render(|&c| if c { "🌲".to_string() } else { "⬛".to_string() })
+}
+
+/// Which trees can be seen from outside the grid
+pub fn visible_trees(input: &str) -> Result<Grid<bool>, ParseError> {
+    let tree_grid = tree_heights(input)?;
+    let mut visibility: Grid<bool> = Grid::new_default(false, tree_grid.width(), tree_grid.height());
+    debug!("tree heights:\n{}", render_heights(&tree_grid));
+
+
+    for x in 0..tree_grid.width() {
+        let col = tree_grid.col_at(x);
+        // top to bottom
+        let mut max: i32 = -1;
+        col.iter().enumerate().for_each(|(y, &height)| {
+            if *height > max { 
+                visibility[(x,y)] = true;
+                max = *height;
+            }
+        });
+        // bottom to top
+        max = -1;
+        col.iter().rev().enumerate().for_each(|(y, &height)| {
+            if *height > max { 
+                visibility[(x,tree_grid.height()-y-1)] = true;
+                max = *height;
+            }
+        });
+    }
+
+    for y in 0..tree_grid.height() {
+        let row = tree_grid.row_at(y);
+        let mut max: i32 = -1;
+        row.iter().enumerate().for_each(|(x, &height)| {
+            if height > max { 
+                visibility[(x,y)] = true;
+                max = height;
+            }
+        });
+        max = -1;
+        row.iter().rev().enumerate().for_each(|(x, &height)| {
+            if height > max { 
+                visibility[(tree_grid.width()-1 - x,y)] = true;
+                max = height;
+            }
+        });
+    }
+
+    debug!("visible trees:\n{}", render_visibility(&visibility));
+    Ok(visibility)
+}
+
+pub fn solve_part1(input: &str) -> Result<u32, ParseError> {
+    let visibility = visible_trees(input)?;
+    Ok(visibility.iter().map(|t| *t as u32).sum::<u32>())
+}
+
+pub fn solve_part2(input: &str) -> Result<i32, ParseError> {
+    let tree_grid = tree_heights(input)?;
+    let mut scenic_score: Grid<i32> = Grid::new_default(1, tree_grid.width(), tree_grid.height());
+
+
+    for x in 0..tree_grid.width() {
+        for y in 0..tree_grid.height() {
+            scenic_score[(x, y)] = get_scenic_score(&tree_grid, (x,y)) as i32;
+        }
+    }
+    
+    //for x in 0..tree_grid.width() {
+    //    let tallest_coords = visibility.col_at(x)
+    //        .iter()
+    //        .enumerate()
+    //        .filter(|&(_, &val)| *val)
+    //        .map(|(y, _)| (x, y))
+    //        .collect::<Vec<Coord>>();
+
+    //    tallest_coords.windows(2).for_each(|coords| {
+    //        let diff_y = (coords[1].1 - coords[0].1) as i32;
+    //        scenic_score[coords[0]] *= diff_y;
+    //        scenic_score[coords[1]] *= diff_y;
+    //    })
+    //}
+
+    //for y in 0..tree_grid.height() {
+    //    let tallest_coords = visibility.row_at(y)
+    //        .iter()
+    //        .enumerate()
+    //        .filter(|&(_, &val)| *val)
+    //        .map(|(x, _)| (x, y))
+    //        .collect::<Vec<Coord>>();
+
+    //    tallest_coords.windows(2).for_each(|coords| {
+    //        let diff_x = (coords[1].0 - coords[0].0) as i32;
+    //        scenic_score[coords[0]] *= diff_x;
+    //        scenic_score[coords[1]] *= diff_x;
+    //    })
+    //}
+    Ok(*scenic_score.iter().max().unwrap())
+}
@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+use common::{parse_at, ParseError};
+use tracing::trace;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Coord {
+    x: i64,
+    y: i64,
+}
+
+impl Coord {
+    fn new() -> Self {
+        Self {
+            x: 0,
+            y: 0
+        }
+    }
+}
+
+/// Moves a rope of `rope_len` knots through the motions, calling `on_step` with the knots after
+/// every single step of the head
+fn simulate(input: &str, rope_len: usize, mut on_step: impl FnMut(&[Coord])) -> Result<(), ParseError> {
+    let mut rope = vec![Coord::new(); rope_len];
+
+    for line in input.lines() {
+        let parts: Vec<&str> = line.split(' ').collect();
+        let (dx, dy) = match parts[0] {
+            "L" => (-1, 0),
+            "R" => (1, 0),
+            "U" => (0, 1),
+            "D" => (0, -1),
+            direction => return Err(ParseError::at(input, direction, "a direction L, R, U or D")),
+        };
+        let steps_str = parts.get(1).ok_or_else(|| ParseError::after(input, line, "a space then a step count"))?;
+        for _ in 0..parse_at::<usize>(input, steps_str, "a step count")? {
+            rope[0].x += dx;
+            rope[0].y += dy;
+
+            for i in 1..rope.len() {
+                let diff_x = rope[i-1].x - rope[i].x;
+                let diff_y = rope[i-1].y - rope[i].y;
+            
+                if diff_x.abs() > 1 || diff_y.abs() > 1 {
+                    rope[i].x += diff_x.signum();
+                    rope[i].y += diff_y.signum();
+                }
+            }
+            on_step(&rope);
+        }
+        trace!("{}: head at {:?}, tail at {:?}", line, rope[0], rope.last().unwrap());
+    }
+    Ok(())
+}
+
+fn simulate_rope_dynamics(input: &str, rope_len: usize) -> Result<usize, ParseError> {
+    let mut visited: HashSet<Coord> = HashSet::new();
+    simulate(input, rope_len, |rope| {
+        visited.insert(rope.last().unwrap().clone());
+    })?;
+    Ok(visited.len())
+}
+
+/// Every knot's `(x, y)` after each step of the head, with y pointing up
+pub fn rope_steps(input: &str, rope_len: usize) -> Result<Vec<Vec<(i64, i64)>>, ParseError> {
+    let mut steps = Vec::new();
+    simulate(input, rope_len, |rope| steps.push(rope.iter().map(|knot| (knot.x, knot.y)).collect()))?;
+    Ok(steps)
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    simulate_rope_dynamics(input, 2)
+}
+
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    simulate_rope_dynamics(input, 10)
+}
@@ -1,59 +1,15 @@
-use std::fs;
 use std::env;
-use std::collections::HashSet;
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-struct Coord {
-    x: i64,
-    y: i64,
-}
-
-impl Coord {
-    fn new() -> Self {
-        Self {
-            x: 0,
-            y: 0
-        }
-    }
-}
+use common::{init_tracing, run_inputs, take_verbosity, ParseError};
 
-fn simulate_rope_dynamics(input: &str, rope_len: usize) -> usize {
-    let mut rope = vec![Coord::new(); rope_len];
-    let mut visited: HashSet<Coord> = HashSet::new();
-
-    input.lines().for_each(|line| {
-        let parts: Vec<&str> = line.split(' ').collect();
-        for _ in 0..parts[1].parse::<usize>().unwrap() {
-            match parts[0] {
-                "L" => rope[0].x -= 1,
-                "R" => rope[0].x += 1,
-                "U" => rope[0].y += 1,
-                "D" => rope[0].y -= 1,
-                _ => panic!()
-            }
-
-            for i in 1..rope.len() {
-                let diff_x = rope[i-1].x - rope[i].x;
-                let diff_y = rope[i-1].y - rope[i].y;
-            
-                if diff_x.abs() > 1 || diff_y.abs() > 1 {
-                    rope[i].x += diff_x.signum();
-                    rope[i].y += diff_y.signum();
-                }
-            }
-            visited.insert(rope.last().unwrap().clone());
-        }
-    });
-    visited.len()
+fn run(input: &str) -> Result<(), ParseError> {
+    println!("Tail of 2 knots visited {} coords", day9::solve_part1(input)?);
+    println!("Tail of 10 knots visited {} coords", day9::solve_part2(input)?);
+    Ok(())
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    assert_eq!(args.len(), 2, "Need to provide an input file as a second argument. \
-                               Number of arguments is not 2");
-    
-    let input = fs::read_to_string(&args[1]).unwrap();
-
-    println!("Tail visited {} coords", simulate_rope_dynamics(&input, 2));
-    println!("Tail visited {} coords", simulate_rope_dynamics(&input, 10));
+    let mut args: Vec<String> = env::args().collect();
+    init_tracing(take_verbosity(&mut args), "day9");
+    run_inputs(&args, run);
 }
@@ -0,0 +1,13 @@
+//! Monkey notes either fail to parse or survive being written back out
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(notes) = day11::parse_notes(input) {
+        let rendered = day11::render_notes(&notes);
+        let reparsed = day11::parse_notes(&rendered)
+            .unwrap_or_else(|e| panic!("rendered notes don't parse: {}\n{}", e, rendered));
+        assert_eq!(reparsed, notes, "mis-parse, rendered as\n{}", rendered);
+    }
+});
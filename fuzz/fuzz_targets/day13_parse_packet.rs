@@ -0,0 +1,13 @@
+//! Packets either fail to parse or survive being written back out
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(packet) = day13::parse_packet(input, input) {
+        let rendered = packet.to_string();
+        let reparsed = day13::parse_packet(&rendered, &rendered)
+            .unwrap_or_else(|e| panic!("rendered packet doesn't parse: {}\n{}", e, rendered));
+        assert_eq!(reparsed, packet, "mis-parse, rendered as {}", rendered);
+    }
+});
@@ -0,0 +1,13 @@
+//! Crate diagrams and moves either fail to parse or survive being written back out
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok((stacks, instructions)) = day5::parse(input) {
+        let rendered = day5::render(&stacks, &instructions);
+        let reparsed = day5::parse(&rendered)
+            .unwrap_or_else(|e| panic!("rendered input doesn't parse: {}\n{}", e, rendered));
+        assert_eq!(reparsed, (stacks, instructions), "mis-parse, rendered as\n{}", rendered);
+    }
+});
@@ -0,0 +1,190 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::Grid;
+
+/// A pixel colour, red, green then blue
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const RED: Rgb = [220, 40, 40];
+
+/// Blends from `low` to `high` as `value` goes from 0 to `max`
+pub fn shade(value: u32, max: u32, low: Rgb, high: Rgb) -> Rgb {
+    let t = value.min(max) as f32 / max.max(1) as f32;
+    let mut rgb = BLACK;
+    for i in 0..3 {
+        rgb[i] = (low[i] as f32 + (high[i] as f32 - low[i] as f32) * t).round() as u8;
+    }
+    rgb
+}
+
+/// Image formats grids can be written as, picked from the file extension
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Svg,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "ppm" => Some(Format::Ppm),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+impl Grid<Rgb> {
+    /// Binary PPM with one pixel per cell
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width(), self.height())?;
+        for pixel in self.iter() {
+            out.write_all(pixel)?;
+        }
+        Ok(())
+    }
+
+    /// SVG with a `cell` pixel square per cell, so it stays crisp when scaled
+    pub fn write_svg(&self, mut out: impl Write, cell: usize) -> io::Result<()> {
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" shape-rendering="crispEdges">"#,
+            self.width() * cell,
+            self.height() * cell
+        )?;
+        for (i, [r, g, b]) in self.iter().enumerate() {
+            let (x, y) = self.coord_at(i);
+            writeln!(
+                out,
+                r##"<rect x="{}" y="{}" width="{cell}" height="{cell}" fill="#{:02x}{:02x}{:02x}"/>"##,
+                x * cell,
+                y * cell,
+                r,
+                g,
+                b
+            )?;
+        }
+        writeln!(out, "</svg>")
+    }
+
+    pub fn write_as(&self, out: impl Write, format: Format) -> io::Result<()> {
+        match format {
+            Format::Ppm => self.write_ppm(out),
+            Format::Svg => self.write_svg(out, 8),
+        }
+    }
+
+    /// Writes the image to `path` in the format its extension names
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let format = Format::from_path(path).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, format!("{} is not a .ppm or .svg file", path.display()))
+        })?;
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_as(&mut out, format)?;
+        out.flush()
+    }
+}
+
+/// Every `every`th frame starting with the first, and always the last so the final state shows
+pub fn every_nth<T>(frames: impl Iterator<Item = T>, every: usize) -> impl Iterator<Item = T> {
+    let every = every.max(1);
+    let mut frames = frames.enumerate().peekable();
+    std::iter::from_fn(move || loop {
+        let (i, frame) = frames.next()?;
+        if i % every == 0 || frames.peek().is_none() {
+            return Some(frame);
+        }
+    })
+}
+
+/// Numbered images of a simulation, `frame_00000.ppm`, `frame_00001.ppm`, ... in one directory
+pub struct Frames {
+    dir: PathBuf,
+    format: Format,
+    count: usize,
+}
+
+impl Frames {
+    pub fn new(dir: impl Into<PathBuf>, format: Format) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir, format, count: 0 })
+    }
+
+    /// Writes the next frame, returning where it went
+    pub fn push(&mut self, frame: &Grid<Rgb>) -> io::Result<PathBuf> {
+        let path = self.dir.join(format!("frame_{:05}.{}", self.count, self.format.extension()));
+        let mut out = BufWriter::new(File::create(&path)?);
+        frame.write_as(&mut out, self.format)?;
+        out.flush()?;
+        self.count += 1;
+        Ok(path)
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Three wide and two high, each pixel different
+    fn picture() -> Grid<Rgb> {
+        Grid::parse("abc\ndef\n", "a letter", |c| Some([c as u8, 0, 0])).unwrap()
+    }
+
+    #[test]
+    fn ppm_has_a_header_then_three_bytes_a_pixel() {
+        let mut out = vec![];
+        picture().write_ppm(&mut out).unwrap();
+        let header = b"P6\n3 2\n255\n";
+        assert_eq!(&out[..header.len()], header);
+        assert_eq!(out.len(), header.len() + 3 * 2 * 3);
+        assert_eq!(&out[header.len()..header.len() + 6], b"a\0\0b\0\0");
+    }
+
+    #[test]
+    fn svg_has_a_rect_per_pixel() {
+        let mut out = vec![];
+        picture().write_svg(&mut out, 8).unwrap();
+        let svg = String::from_utf8(out).unwrap();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="16""#));
+        assert_eq!(svg.matches("<rect ").count(), 6);
+        assert!(svg.contains(r##"<rect x="16" y="8" width="8" height="8" fill="#660000"/>"##));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn frames_are_numbered_in_order() {
+        let dir = std::env::temp_dir().join(format!("grid-frames-{}", std::process::id()));
+        let mut frames = Frames::new(&dir, Format::Ppm).unwrap();
+        assert_eq!(frames.push(&picture()).unwrap(), dir.join("frame_00000.ppm"));
+        assert_eq!(frames.push(&picture()).unwrap(), dir.join("frame_00001.ppm"));
+        assert_eq!(frames.count(), 2);
+        assert_eq!(fs::read(dir.join("frame_00001.ppm")).unwrap().len(), 11 + 18);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn every_nth_keeps_the_last_frame() {
+        let kept = |n: usize, every| every_nth(0..n, every).collect::<Vec<usize>>();
+        assert_eq!(kept(24, 5), [0, 5, 10, 15, 20, 23]);
+        assert_eq!(kept(21, 5), [0, 5, 10, 15, 20]);
+        assert_eq!(kept(3, 1), [0, 1, 2]);
+        assert_eq!(kept(1, 5), [0]);
+        assert!(kept(0, 5).is_empty());
+    }
+}
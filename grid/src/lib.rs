@@ -0,0 +1,286 @@
+//! Rectangular grids stored row-major, as used by the map puzzles, and images of them
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use common::{grid_size, ParseError};
+
+pub mod image;
+
+/// `(x, y)` with the origin in the top left corner
+pub type Coord = (usize, usize);
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> where T: Clone {
+    pub fn new_default(val: T, width: usize, height: usize) -> Self {
+        Self {
+            data: vec![val; width*height],
+            width,
+            height
+        }
+    }
+
+    /// Rows become columns, so `(x, y)` moves to `(y, x)`
+    pub fn transpose(&self) -> Self {
+        let data = (0..self.width)
+            .flat_map(|x| self.col_at(x).into_iter().cloned())
+            .collect();
+        Self { data, width: self.height, height: self.width }
+    }
+
+    /// Quarter turn clockwise, so the first column becomes the first row reversed
+    pub fn rotate_cw(&self) -> Self {
+        let data = (0..self.width)
+            .flat_map(|x| self.col_at(x).into_iter().rev().cloned())
+            .collect();
+        Self { data, width: self.height, height: self.width }
+    }
+
+    /// Quarter turn anticlockwise, so the last column becomes the first row
+    pub fn rotate_ccw(&self) -> Self {
+        let data = (0..self.width).rev()
+            .flat_map(|x| self.col_at(x).into_iter().cloned())
+            .collect();
+        Self { data, width: self.height, height: self.width }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a map with one cell per character, rejecting ragged rows and any
+    /// character `cell` has no value for
+    pub fn parse(input: &str, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let (width, height) = grid_size(input)?;
+        let mut data = Vec::with_capacity(width * height);
+        for row in input.lines() {
+            for (i, c) in row.char_indices() {
+                match cell(c) {
+                    Some(val) => data.push(val),
+                    None => return Err(ParseError::at(input, &row[i..i + c.len_utf8()], expected)),
+                }
+            }
+        }
+
+        Ok(Self {
+            data,
+            width,
+            height
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn coord_at(&self, i: usize) -> Coord {
+        (i%self.width, i/self.width)
+    }
+
+    pub fn index_of(&self, coord: Coord) -> usize {
+        coord.0 + coord.1 * self.width
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.0 < self.width && coord.1 < self.height
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        if self.contains(coord) { self.data.get(self.index_of(coord)) } else { None }
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        if self.contains(coord) {
+            let i = self.index_of(coord);
+            self.data.get_mut(i)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Cells in column `x`, top to bottom; empty if there is no such column
+    pub fn col_at(&self, x: usize) -> Vec<&T> {
+        if x >= self.width {
+            return vec![];
+        }
+        self.data.iter().skip(x).step_by(self.width).collect()
+    }
+
+    pub fn row_at(&self, y: usize) -> &[T] {
+        &self.data[y*self.width..y*self.width+self.width]
+    }
+
+    /// Cells in row-major order
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.data.iter().position(pred).map(|i| self.coord_at(i))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height
+        }
+    }
+
+    /// Up, down, left and right neighbours that lie inside the grid
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.offsets(coord, &[(0, -1), (0, 1), (-1, 0), (1, 0)])
+    }
+
+    /// Orthogonal and diagonal neighbours that lie inside the grid
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.offsets(coord, &[(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)])
+    }
+
+    fn offsets(&self, coord: Coord, deltas: &'static [(isize, isize)]) -> impl Iterator<Item = Coord> + '_ {
+        deltas.iter().filter_map(move |&(dx, dy)| {
+            let x = coord.0.checked_add_signed(dx)?;
+            let y = coord.1.checked_add_signed(dy)?;
+            Some((x, y)).filter(|&c| self.contains(c))
+        })
+    }
+
+    /// Renders one line per row, joining what `cell` makes of each cell
+    pub fn render(&self, cell: impl Fn(&T) -> String) -> String {
+        (0..self.height)
+            .map(|y| self.row_at(y).iter().map(&cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+    fn index(&self, coord: Coord) -> &T {
+        assert!(self.contains(coord));
+        &self.data[self.index_of(coord)]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        assert!(self.contains(coord));
+        let i = self.index_of(coord);
+        &mut self.data[i]
+    }
+}
+
+/// Row-major index, as returned by `index_of`
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+    fn index(&self, i: usize) -> &T {
+        &self.data[i]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.data[i]
+    }
+}
+
+impl<T> fmt::Debug for Grid<T>
+where T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.render(|c| format!("[{:?}]", c)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Three wide and two high, so mixing up width and height shows
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef\n", "a letter", Some).unwrap()
+    }
+
+    fn rows(grid: &Grid<char>) -> String {
+        grid.render(|c| c.to_string())
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let grid = letters();
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(rows(&transposed), "ad\nbe\ncf");
+        assert_eq!(transposed[(1, 2)], grid[(2, 1)]);
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn rotations_turn_a_quarter_each_way() {
+        let grid = letters();
+        assert_eq!(rows(&grid.rotate_cw()), "da\neb\nfc");
+        assert_eq!(rows(&grid.rotate_ccw()), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_ccw().rotate_cw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.rotate_ccw().rotate_ccw());
+        assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), grid);
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = Grid::new_default(0, 4, 3);
+        let counts = |coord| (grid.neighbours4(coord).count(), grid.neighbours8(coord).count());
+        assert_eq!(counts((0, 0)), (2, 3));
+        assert_eq!(counts((3, 2)), (2, 3));
+        assert_eq!(counts((3, 0)), (2, 3));
+        assert_eq!(counts((1, 0)), (3, 5));
+        assert_eq!(counts((0, 1)), (3, 5));
+        assert_eq!(counts((1, 1)), (4, 8));
+
+        let mut around = grid.neighbours8((3, 1)).collect::<Vec<Coord>>();
+        around.sort();
+        assert_eq!(around, vec![(2, 0), (2, 1), (2, 2), (3, 0), (3, 2)]);
+    }
+
+    #[test]
+    fn get_rejects_coords_outside_the_grid() {
+        let mut grid = letters();
+        assert_eq!(grid.get((2, 1)), Some(&'f'));
+        // (3, 0) would wrap round to the start of the second row if only the index were checked
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.get_mut((3, 0)), None);
+
+        *grid.get_mut((0, 1)).unwrap() = 'x';
+        assert_eq!(rows(&grid), "abc\nxef");
+    }
+
+    #[test]
+    fn columns_of_empty_and_missing_columns() {
+        let grid = letters();
+        assert_eq!(grid.col_at(1), vec![&'b', &'e']);
+        assert!(grid.col_at(3).is_empty());
+
+        let empty = Grid::new_default('.', 0, 2);
+        assert!(empty.col_at(0).is_empty());
+        assert_eq!(empty.render(|c| c.to_string()), "\n");
+        assert_eq!(empty.transpose().height(), 0);
+    }
+}
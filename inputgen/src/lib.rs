@@ -0,0 +1,293 @@
+//! Random but well-formed puzzle inputs for stress testing the solvers
+//!
+//! Every generator takes a `size` that scales the puzzle roughly linearly (elves, lines, groups,
+//! characters, moves, instructions, directories, grid side, monkeys or packet pairs) and returns
+//! the input as the puzzle would print it, ending in a newline.
+
+use std::fmt::Write;
+
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Days that have a generator
+pub const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+
+/// Generates an input for `day`, the same one every time for a given seed and size
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let rng = &mut rng;
+    let input = match day {
+        1 => calories(rng, size),
+        2 => strategy_guide(rng, size),
+        3 => rucksacks(rng, size),
+        4 => section_pairs(rng, size),
+        5 => crate_moves(rng, size),
+        6 => datastream(rng, size),
+        7 => terminal_transcript(rng, size),
+        8 => tree_heights(rng, size),
+        9 => rope_motions(rng, size),
+        10 => crt_program(rng, size),
+        11 => monkey_notes(rng, size),
+        12 => heightmap(rng, size),
+        13 => packet_pairs(rng, size),
+        _ => return None,
+    };
+    Some(input)
+}
+
+/// Day 1: `elves` groups of calorie counts separated by blank lines
+pub fn calories(rng: &mut impl Rng, elves: usize) -> String {
+    (0..elves.max(1))
+        .map(|_| {
+            (0..rng.gen_range(1..=15))
+                .map(|_| format!("{}\n", rng.gen_range(1000..=70000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Day 2: `rounds` lines of an opponent move and a response
+pub fn strategy_guide(rng: &mut impl Rng, rounds: usize) -> String {
+    (0..rounds.max(1))
+        .map(|_| format!("{} {}\n", *['A', 'B', 'C'].choose(rng).unwrap(), *['X', 'Y', 'Z'].choose(rng).unwrap()))
+        .collect()
+}
+
+/// Day 3: `groups` groups of three rucksacks, each with one item type in both compartments and
+/// one badge shared by the whole group
+pub fn rucksacks(rng: &mut impl Rng, groups: usize) -> String {
+    let mut letters = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+    let mut input = String::new();
+    for _ in 0..groups.max(1) {
+        // Each rucksack draws on its own 17 letters besides the badge, so the badge is the only
+        // item all three share
+        letters.shuffle(rng);
+        let badge = letters[0];
+        for pool in letters[1..].chunks(17) {
+            let shared = if rng.gen_bool(0.2) { badge } else { pool[0] };
+            let (first_pool, second_pool) = pool[1..].split_at(8);
+            let len = rng.gen_range(1..=12);
+            let mut first = (0..len).map(|_| *first_pool.choose(rng).unwrap()).collect::<Vec<char>>();
+            let mut second = (0..len).map(|_| *second_pool.choose(rng).unwrap()).collect::<Vec<char>>();
+            first.insert(rng.gen_range(0..=len), shared);
+            second.insert(rng.gen_range(0..=len), shared);
+            if shared != badge {
+                // The badge goes in one compartment and another item in the other, keeping
+                // them the same size
+                let (with_badge, other, other_pool) = if rng.gen() {
+                    (&mut first, &mut second, second_pool)
+                } else {
+                    (&mut second, &mut first, first_pool)
+                };
+                with_badge.insert(rng.gen_range(0..=with_badge.len()), badge);
+                other.insert(rng.gen_range(0..=other.len()), *other_pool.choose(rng).unwrap());
+            }
+            writeln!(input, "{}{}", first.iter().collect::<String>(), second.iter().collect::<String>()).unwrap();
+        }
+    }
+    input
+}
+
+/// Day 4: `pairs` lines of two section ranges
+pub fn section_pairs(rng: &mut impl Rng, pairs: usize) -> String {
+    let mut range = || {
+        let start = rng.gen_range(1..=99);
+        format!("{}-{}", start, rng.gen_range(start..=99))
+    };
+    (0..pairs.max(1)).map(|_| format!("{},{}\n", range(), range())).collect()
+}
+
+/// Day 5: up to nine stacks of crates and `moves` moves that never take more crates than a
+/// stack holds
+pub fn crate_moves(rng: &mut impl Rng, moves: usize) -> String {
+    let stack_count = rng.gen_range(1..=9);
+    let mut heights = (0..stack_count).map(|_| rng.gen_range(0..=8)).collect::<Vec<usize>>();
+    heights[0] = heights[0].max(1);
+
+    let mut input = String::new();
+    let tallest = *heights.iter().max().unwrap();
+    for level in (0..tallest).rev() {
+        let row = heights
+            .iter()
+            .map(|&height| {
+                if level < height { format!("[{}]", rng.gen_range('A'..='Z')) } else { "   ".to_string() }
+            })
+            .collect::<Vec<_>>();
+        writeln!(input, "{}", row.join(" ")).unwrap();
+    }
+    let numbers = (1..=stack_count).map(|n| format!(" {} ", n)).collect::<Vec<_>>();
+    writeln!(input, "{}\n", numbers.join(" ")).unwrap();
+
+    for _ in 0..moves {
+        // A move shuffles crates around without changing the total, so a stack to take from
+        // always exists
+        let from = loop {
+            let from = rng.gen_range(0..stack_count);
+            if heights[from] > 0 {
+                break from;
+            }
+        };
+        let to = rng.gen_range(0..stack_count);
+        let mv = rng.gen_range(1..=heights[from]);
+        heights[from] -= mv;
+        heights[to] += mv;
+        writeln!(input, "move {} from {} to {}", mv, from + 1, to + 1).unwrap();
+    }
+    input
+}
+
+/// Day 6: a datastream of at least `len` letters, mostly a handful of letters repeating with
+/// fourteen distinct ones somewhere, so both markers exist
+pub fn datastream(rng: &mut impl Rng, len: usize) -> String {
+    let len = len.max(14);
+    let mut letters = ('a'..='z').collect::<Vec<char>>();
+    letters.shuffle(rng);
+    let mut stream = (0..len).map(|_| letters[rng.gen_range(0..4)]).collect::<Vec<char>>();
+    let start = rng.gen_range(0..=len - 14);
+    letters.shuffle(rng);
+    stream[start..start + 14].copy_from_slice(&letters[..14]);
+    stream.into_iter().collect::<String>() + "\n"
+}
+
+/// Day 7: a depth first walk of a random tree of `dirs` directories, each listing at least one file
+pub fn terminal_transcript(rng: &mut impl Rng, dirs: usize) -> String {
+    // parent[i] is the directory that directory i sits in, the root being directory 0
+    let parents = (1..dirs.max(1)).map(|i| rng.gen_range(0..i)).collect::<Vec<usize>>();
+    let children = |dir: usize| {
+        parents.iter().enumerate().filter(move |&(_, &parent)| parent == dir).map(|(i, _)| i + 1)
+    };
+
+    fn walk(rng: &mut impl Rng, input: &mut String, dir: usize, children: &dyn Fn(usize) -> Vec<usize>) {
+        input.push_str("$ ls\n");
+        for child in children(dir) {
+            writeln!(input, "dir d{}", child).unwrap();
+        }
+        for file in 0..rng.gen_range(1..=4) {
+            writeln!(input, "{} f{}.txt", rng.gen_range(1..=300000), file).unwrap();
+        }
+        for child in children(dir) {
+            writeln!(input, "$ cd d{}", child).unwrap();
+            walk(rng, input, child, children);
+            input.push_str("$ cd ..\n");
+        }
+    }
+
+    let mut input = "$ cd /\n".to_string();
+    walk(rng, &mut input, 0, &|dir| children(dir).collect());
+    input
+}
+
+/// Day 8: a `side` by `side` grid of tree heights
+pub fn tree_heights(rng: &mut impl Rng, side: usize) -> String {
+    (0..side.max(1))
+        .map(|_| (0..side.max(1)).map(|_| char::from(b'0' + rng.gen_range(0..=9))).collect::<String>() + "\n")
+        .collect()
+}
+
+/// Day 9: `moves` lines of a direction and a step count
+pub fn rope_motions(rng: &mut impl Rng, moves: usize) -> String {
+    (0..moves.max(1))
+        .map(|_| format!("{} {}\n", *['L', 'R', 'U', 'D'].choose(rng).unwrap(), rng.gen_range(1..=20)))
+        .collect()
+}
+
+/// Day 10: `instructions` lines of `noop` and `addx`, keeping X within the 40 pixel wide screen
+pub fn crt_program(rng: &mut impl Rng, instructions: usize) -> String {
+    let mut x: i64 = 1;
+    let mut program = String::new();
+    for _ in 0..instructions.max(1) {
+        if rng.gen_bool(0.3) {
+            program.push_str("noop\n");
+            continue;
+        }
+        let value = loop {
+            let value = rng.gen_range(-10..=10);
+            if value != 0 && (0..40).contains(&(x + value)) {
+                break value;
+            }
+        };
+        x += value;
+        writeln!(program, "addx {}", value).unwrap();
+    }
+    program
+}
+
+/// Day 11: `monkeys` monkeys with distinct prime divisors, throwing only to other monkeys
+///
+/// Operations are kept small so that 20 rounds of part 1 stay within `u128` with exact worry
+/// levels, which the day's tests check against.
+pub fn monkey_notes(rng: &mut impl Rng, monkeys: usize) -> String {
+    const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    let monkeys = monkeys.clamp(2, PRIMES.len());
+    let mut divisors = PRIMES.to_vec();
+    divisors.shuffle(rng);
+
+    let mut notes = Vec::with_capacity(monkeys);
+    for (i, divisor) in divisors.iter().take(monkeys).enumerate() {
+        let items = (0..rng.gen_range(1..=6)).map(|_| rng.gen_range(50..100).to_string()).collect::<Vec<_>>();
+        let operation = match rng.gen_range(0..3) {
+            0 => format!("old + {}", rng.gen_range(1..=9)),
+            1 => format!("old * {}", rng.gen_range(2..=3)),
+            _ => "old + old".to_string(),
+        };
+        let mut other = || loop {
+            let target = rng.gen_range(0..monkeys);
+            if target != i {
+                break target;
+            }
+        };
+        let (if_true, if_false) = (other(), other());
+        notes.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            i, items.join(", "), operation, divisor, if_true, if_false
+        ));
+    }
+    notes.join("\n")
+}
+
+/// Day 12: a heightmap at least 26 wide whose S in the first column can always climb to the E
+/// in the last
+pub fn heightmap(rng: &mut impl Rng, side: usize) -> String {
+    let width = side.max(26);
+    let height = side.max(2);
+    let mut map = (0..height)
+        .map(|_| (0..width).map(|_| rng.gen_range(b'a'..=b'z')).collect::<Vec<u8>>())
+        .collect::<Vec<_>>();
+
+    // A staircase from S to E: heights along it rise by at most one per step, reaching z at E
+    let (mut x, mut y) = (0, rng.gen_range(0..height));
+    let end = (width - 1, rng.gen_range(0..height));
+    let mut steps = vec![(1, 0); end.0];
+    steps.extend(vec![(0, if end.1 > y { 1 } else { -1 }); end.1.abs_diff(y)]);
+    steps.shuffle(rng);
+    let path_len = steps.len();
+
+    map[y][x] = b'S';
+    for (i, (dx, dy)) in steps.into_iter().enumerate() {
+        x = x.wrapping_add_signed(dx);
+        y = y.wrapping_add_signed(dy);
+        map[y][x] = b'a' + ((i + 1) * 25 / path_len) as u8;
+    }
+    map[end.1][end.0] = b'E';
+
+    map.into_iter().map(|row| String::from_utf8(row).unwrap() + "\n").collect()
+}
+
+/// Day 13: `pairs` pairs of nested packets separated by blank lines
+pub fn packet_pairs(rng: &mut impl Rng, pairs: usize) -> String {
+    (0..pairs.max(1))
+        .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn packet(rng: &mut impl Rng, depth: usize) -> String {
+    let items = (0..rng.gen_range(0..=5))
+        .map(|_| {
+            if depth < 4 && rng.gen_bool(0.3) { packet(rng, depth + 1) } else { rng.gen_range(0..=10).to_string() }
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", items.join(","))
+}